@@ -2,6 +2,7 @@ use crate::config::Config;
 use crate::drawing::Drawing;
 use crate::error::*;
 use crate::library::Library;
+use crate::pattern;
 use crate::symbol::Symbol;
 
 #[derive(Debug)]
@@ -25,6 +26,7 @@ impl Component {
             .packages
             .get_handler(&config.get_string("package.type")?)?;
         let pattern = package_handler.draw_pattern(&config, &lib.config)?;
+        let pattern = pattern::finish(pattern, config, &lib.config);
         let model = package_handler.draw_model(&config, &lib.config)?;
         let digest = config.calc_digest();
         Ok(Component {
@@ -9,7 +9,7 @@ use yaml_rust::{yaml, Yaml, YamlEmitter, YamlLoader};
 
 use crate::error::*;
 
-#[derive(Debug, Default)]
+#[derive(Clone, Copy, Debug, Default)]
 pub struct Range(pub f64, pub f64);
 
 impl Range {
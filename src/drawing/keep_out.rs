@@ -0,0 +1,35 @@
+use super::{Layer, Point, Transform, Transformation};
+
+/// Rectangular area where tracks, vias and copper pours are not allowed.
+#[derive(Clone, Default, Debug)]
+pub struct KeepOut {
+    pub p: (Point, Point),
+    pub layer: Layer,
+}
+
+impl KeepOut {
+    /// Creates a new `KeepOut`.
+    pub fn new(x0: f64, y0: f64, x1: f64, y1: f64) -> Self {
+        KeepOut {
+            p: (
+                Point::new(x0.min(x1), y0.min(y1)),
+                Point::new(x0.max(x1), y0.max(y1)),
+            ),
+            layer: Layer::NONE,
+        }
+    }
+
+    /// Builds a `KeepOut` with modified copper layers.
+    #[inline]
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+}
+
+impl Transform for KeepOut {
+    fn transform(mut self, t: &Transformation) -> Self {
+        self.p = (self.p.0.transform(t), self.p.1.transform(t));
+        self
+    }
+}
@@ -2,7 +2,9 @@ pub mod prelude;
 
 mod attribute;
 mod box3d;
+mod circle;
 mod geometry;
+mod keep_out;
 mod line;
 mod pad;
 mod rect;
@@ -18,7 +20,9 @@ pub use prelude::*;
 
 pub use attribute::Attribute;
 pub use box3d::Box3D;
+pub use circle::Circle;
 pub use geometry::*;
+pub use keep_out::KeepOut;
 pub use line::Line;
 pub use pad::*;
 pub use rect::Rect;
@@ -30,6 +34,8 @@ use svg::*;
 pub enum Element {
     Attribute(Attribute),
     Box3D(Box3D),
+    Circle(Circle),
+    KeepOut(KeepOut),
     Line(Line),
     Pad(Pad),
     SymbolPin(SymbolPin),
@@ -40,6 +46,8 @@ impl Transform for Element {
         match self {
             Element::Attribute(a) => Element::Attribute(a.transform(t)),
             Element::Box3D(b) => Element::Box3D(b), // Don't apply 2D transformation
+            Element::Circle(c) => Element::Circle(c.transform(t)),
+            Element::KeepOut(k) => Element::KeepOut(k.transform(t)),
             Element::Line(l) => Element::Line(l.transform(t)),
             Element::Pad(p) => Element::Pad(p.transform(t)),
             Element::SymbolPin(p) => Element::SymbolPin(p.transform(t)),
@@ -138,6 +146,20 @@ impl Drawing {
         self.elements.push(Element::Box3D(box3d));
     }
 
+    /// Adds a circle object to the drawing.
+    #[inline]
+    pub fn add_circle(&mut self, circle: Circle) {
+        self.elements
+            .push(Element::Circle(circle.transform(&self.canvas_transform)));
+    }
+
+    /// Adds a keep-out area to the drawing.
+    #[inline]
+    pub fn add_keep_out(&mut self, keep_out: KeepOut) {
+        self.elements
+            .push(Element::KeepOut(keep_out.transform(&self.canvas_transform)));
+    }
+
     /// Adds a line object to the drawing.
     #[inline]
     pub fn add_line(&mut self, line: Line) {
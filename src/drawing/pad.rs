@@ -1,10 +1,19 @@
+use crate::config::Config;
+
 use super::*;
 
 #[derive(Clone, Debug)]
 pub enum PadShape {
     Circle,
+    Oval,
+    /// Polygon with vertices relative to the pad origin.
+    Polygon(Vec<Point>),
     Rect,
-    RoundRect,
+    /// Rectangle with rounded corners, the value is a ratio of the corner radius to the
+    /// smaller side. Zero ratio is replaced with the configured one by `Pad::round_corners`.
+    RoundRect(f64),
+    /// Trapezoid made of a rectangle by moving its vertices by the specified deltas.
+    Trapezoid(f64, f64),
 }
 
 impl Default for PadShape {
@@ -14,6 +23,13 @@ impl Default for PadShape {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PadKind {
+    Smd,
+    ThroughHole,
+    NonPlated,
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct Pad {
     pub name: String,
@@ -23,6 +39,8 @@ pub struct Pad {
     pub hole: Option<Size>,
     pub layers: Layer,
     pub mask: f64,
+    pub paste_windows: Vec<Rect>,
+    pub rotation: f64,
 }
 
 impl Pad {
@@ -34,12 +52,77 @@ impl Pad {
         }
     }
 
+    /// Returns the size of the pad bounding box taking its rotation into account.
+    pub fn extent(&self) -> Size {
+        let a = self.rotation.to_radians();
+        let (sin, cos) = (a.sin().abs(), a.cos().abs());
+        Size::new(
+            self.size.x * cos + self.size.y * sin,
+            self.size.x * sin + self.size.y * cos,
+        )
+    }
+
+    /// Builds a `Pad` with modified hole size.
+    #[inline]
+    pub fn hole(mut self, x: f64, y: f64) -> Self {
+        self.hole = Some(Size::new(x, y));
+        self
+    }
+
+    /// Returns the pad kind.
+    ///
+    /// A pad with a hole but without copper is a non-plated hole.
+    pub fn kind(&self) -> PadKind {
+        if self.hole.is_none() {
+            PadKind::Smd
+        } else if self
+            .layers
+            .intersects(Layer::COPPER_TOP | Layer::COPPER_BOTTOM)
+        {
+            PadKind::ThroughHole
+        } else {
+            PadKind::NonPlated
+        }
+    }
+
+    /// Builds a non-plated hole of the specified diameter.
+    pub fn non_plated(mut self, diameter: f64) -> Self {
+        self.hole = Some(Size::new(diameter, diameter));
+        self.size = Size::new(diameter, diameter);
+        self.shape = PadShape::Circle;
+        self.layers = Layer::MASK_TOP | Layer::MASK_BOTTOM;
+        self
+    }
+
     /// Returns `true` if `Pad` has surface mount type.
     #[inline]
     pub fn is_smd(&self) -> bool {
         self.hole.is_none()
     }
 
+    /// Builds a `Pad` with rounded corners if it is rectangular and surface mount.
+    ///
+    /// Corner radius is `pattern.ratio.corner-to-width` of the smaller pad side but not more
+    /// than `pattern.maximum.corner-radius`. Nothing is changed unless `pattern.rounded-pads`
+    /// is set, except for `RoundRect` pads without a ratio.
+    pub fn round_corners(mut self, lib_cfg: &Config) -> Self {
+        let rounded = lib_cfg.get_bool("pattern.rounded-pads").unwrap();
+        let ratio = lib_cfg.get_f64("pattern.ratio.corner-to-width").unwrap();
+        let max_radius = lib_cfg.get_f64("pattern.maximum.corner-radius").unwrap();
+        let width = self.size.x.min(self.size.y);
+        let ratio = if width > 0.0 {
+            ratio.min(max_radius / width)
+        } else {
+            ratio
+        };
+        self.shape = match self.shape {
+            PadShape::Rect if rounded && self.is_smd() => PadShape::RoundRect(ratio),
+            PadShape::RoundRect(r) if r <= 0.0 => PadShape::RoundRect(ratio),
+            shape => shape,
+        };
+        self
+    }
+
     /// Builds a `Pad` with modified layers.
     #[inline]
     pub fn layers(mut self, layers: Layer) -> Self {
@@ -61,6 +144,13 @@ impl Pad {
         self
     }
 
+    /// Builds a `Pad` rotated by the specified angle (in degrees) counter-clockwise.
+    #[inline]
+    pub fn rotation(mut self, angle: f64) -> Self {
+        self.rotation = angle;
+        self
+    }
+
     /// Builds a `Pad` with modified shape.
     #[inline]
     pub fn shape(mut self, shape: PadShape) -> Self {
@@ -68,18 +158,54 @@ impl Pad {
         self
     }
 
+    /// Builds a `Pad` with modified paste windows.
+    ///
+    /// Windows are placed relative to the pad origin. If there are no windows, paste
+    /// covers the whole pad.
+    #[inline]
+    pub fn paste_windows(mut self, windows: Vec<Rect>) -> Self {
+        self.paste_windows = windows;
+        self
+    }
+
     /// Builds a `Pad` with modified size.
     pub fn size(mut self, x: f64, y: f64) -> Self {
         self.size.x = x;
         self.size.y = y;
         self
     }
+
+    /// Builds a via-style through-hole `Pad` with the specified drill and annular ring.
+    ///
+    /// The via has copper on both sides only. Mask layers are to be added for an exposed via.
+    pub fn via(mut self, drill: f64, ring: f64) -> Self {
+        let diameter = drill + 2.0 * ring;
+        self.hole = Some(Size::new(drill, drill));
+        self.size = Size::new(diameter, diameter);
+        self.shape = PadShape::Circle;
+        self.layers = Layer::COPPER_TOP | Layer::COPPER_BOTTOM;
+        self
+    }
 }
 
 impl Transform for Pad {
     fn transform(mut self, t: &Transformation) -> Self {
         self.origin = self.origin.transform(t);
         self.size = self.size.transform(t);
+        self.hole = self.hole.map(|h| h.transform(t));
+        self.paste_windows = self
+            .paste_windows
+            .into_iter()
+            .map(|w| w.scale(t.scale_x, t.scale_y))
+            .collect();
+        if let PadShape::Polygon(points) = self.shape {
+            self.shape = PadShape::Polygon(
+                points
+                    .into_iter()
+                    .map(|p| p.scale(t.scale_x, t.scale_y))
+                    .collect(),
+            );
+        }
         // TODO: Consider rotation
         self
     }
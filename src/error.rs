@@ -12,6 +12,9 @@ pub enum QedaError {
     #[error("invalid generator type: '{0}'")]
     InvalidGeneratorType(String),
 
+    #[error("invalid lead count: '{0}', {1}")]
+    InvalidLeadCount(u64, &'static str),
+
     #[error("invalid package type: '{0}'")]
     InvalidPackageType(String),
 
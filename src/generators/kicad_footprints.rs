@@ -7,6 +7,12 @@ use crate::config::Config;
 use crate::drawing::*;
 use crate::error::Result;
 
+// Size of the anchor pad of a custom shaped (polygon) pad
+const ANCHOR_SIZE: f64 = 0.1;
+
+// Width of lines marking a keep-out area
+const KEEP_OUT_LINE_WIDTH: f64 = 0.1;
+
 #[derive(Default)]
 pub struct KicadFootprints {}
 
@@ -14,8 +20,21 @@ impl fmt::Display for PadShape {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             PadShape::Circle => write!(f, "circle"),
+            PadShape::Oval => write!(f, "oval"),
+            PadShape::Polygon(_) => write!(f, "custom"),
             PadShape::Rect => write!(f, "rect"),
-            PadShape::RoundRect => write!(f, "roundrect"),
+            PadShape::RoundRect(_) => write!(f, "roundrect"),
+            PadShape::Trapezoid(_, _) => write!(f, "trapezoid"),
+        }
+    }
+}
+
+impl fmt::Display for PadKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PadKind::Smd => write!(f, "smd"),
+            PadKind::ThroughHole => write!(f, "thru_hole"),
+            PadKind::NonPlated => write!(f, "np_thru_hole"),
         }
     }
 }
@@ -80,35 +99,48 @@ impl KicadFootprints {
     pub fn render(self, components: &[Component]) -> Result<()> {
         for component in components {
             let name = &component.name;
-            let pattern = &component.pattern;
             info!("  • foorprint: '{}'", name);
             let mut f = File::create(format!("{}.kicad_mod", name))?;
-            writeln!(f, "(module {name} (layer F.Cu)", name = name)?;
-            for element in &pattern.elements {
-                match element {
-                    Element::Attribute(a) => {
-                        let (kind, value) = match a.id.as_str() {
-                            "ref-des" => ("reference", "REF**".to_string()),
-                            "value" => ("value", name.clone()),
-                            _ => ("user", a.value.clone()),
-                        };
-                        writeln!(
-                            f,
-                            "  (fp_text {kind} {value} (at {x:.3} {y:.3}) (layer {layer})",
-                            kind = kind,
-                            value = value,
-                            x = a.origin.x,
-                            y = a.origin.y,
-                            layer = a.layer,
-                        )?;
-                        writeln!(f, "    (effects (font (size {font_size:.3} {font_size:.3}) (thickness {line_width:.3})))",
+            write_footprint(&mut f, name, &component.pattern)?;
+        }
+        Ok(())
+    }
+
+    /// Builds a `KicadFootprints` with applied settings from `Config`.
+    pub fn settings(self, _lib_cfg: &Config) -> Self {
+        // TODO: Use settings
+        self
+    }
+}
+
+// Write a footprint in the KiCad 5 module format
+fn write_footprint(f: &mut impl Write, name: &str, pattern: &Drawing) -> Result<()> {
+    writeln!(f, "(module {name} (layer F.Cu)", name = name)?;
+    for element in &pattern.elements {
+        match element {
+            Element::Attribute(a) => {
+                let (kind, value) = match a.id.as_str() {
+                    "ref-des" => ("reference", "REF**".to_string()),
+                    "value" => ("value", name.to_string()),
+                    _ => ("user", a.value.clone()),
+                };
+                writeln!(
+                    f,
+                    "  (fp_text {kind} {value} (at {x:.3} {y:.3}) (layer {layer})",
+                    kind = kind,
+                    value = value,
+                    x = a.origin.x,
+                    y = a.origin.y,
+                    layer = a.layer,
+                )?;
+                writeln!(f, "    (effects (font (size {font_size:.3} {font_size:.3}) (thickness {line_width:.3})))",
                             font_size = a.font_size,
                             line_width = a.line_width,
                         )?;
-                        writeln!(f, "  )")?;
-                    }
-                    Element::Line(l) => {
-                        writeln!(
+                writeln!(f, "  )")?;
+            }
+            Element::Line(l) => {
+                writeln!(
                             f,
                             "  (fp_line (start {x0:.3} {y0:.3}) (end {x1:.3} {y1:.3}) (layer {layer}) (width {width:.3}))",
                             x0 = l.p.0.x,
@@ -118,33 +150,310 @@ impl KicadFootprints {
                             layer = l.layer,
                             width = l.width,
                         )?;
+            }
+            Element::Circle(c) => {
+                writeln!(
+                            f,
+                            "  (fp_circle (center {x:.3} {y:.3}) (end {x1:.3} {y:.3}) (layer {layer}) (width {width:.3}))",
+                            x = c.center.x,
+                            y = c.center.y,
+                            x1 = c.center.x + c.radius,
+                            layer = c.layer,
+                            width = c.width,
+                        )?;
+            }
+            Element::KeepOut(k) => {
+                // Module format does not support zones, so the keep-out area is drawn
+                // crossed out on the user drawings layer like in KiCad library modules
+                let (x0, y0, x1, y1) = (k.p.0.x, k.p.0.y, k.p.1.x, k.p.1.y);
+                let lines = [
+                    (x0, y0, x1, y0),
+                    (x1, y0, x1, y1),
+                    (x1, y1, x0, y1),
+                    (x0, y1, x0, y0),
+                    (x0, y0, x1, y1),
+                    (x0, y1, x1, y0),
+                ];
+                for (x0, y0, x1, y1) in lines.iter() {
+                    writeln!(
+                                f,
+                                "  (fp_line (start {x0:.3} {y0:.3}) (end {x1:.3} {y1:.3}) (layer Dwgs.User) (width {width:.3}))",
+                                x0 = x0,
+                                y0 = y0,
+                                x1 = x1,
+                                y1 = y1,
+                                width = KEEP_OUT_LINE_WIDTH,
+                            )?;
+                }
+                writeln!(
+                    f,
+                    "  (fp_text user KEEP-OUT (at {x:.3} {y:.3}) (layer Dwgs.User)",
+                    x = (x0 + x1) / 2.0,
+                    y = (y0 + y1) / 2.0,
+                )?;
+                writeln!(
+                    f,
+                    "    (effects (font (size 1.000 1.000) (thickness 0.150)))"
+                )?;
+                writeln!(f, "  )")?;
+            }
+            Element::Pad(p) => {
+                let paste = p.layers & (Layer::PASTE_TOP | Layer::PASTE_BOTTOM);
+                let layers = if p.paste_windows.is_empty() {
+                    p.layers
+                } else {
+                    p.layers - paste
+                };
+                let drill = match &p.hole {
+                    Some(h) if (h.x - h.y).abs() > f64::EPSILON => {
+                        format!(" (drill oval {:.3} {:.3})", h.x, h.y)
+                    }
+                    Some(h) => format!(" (drill {:.3})", h.x),
+                    None => String::new(),
+                };
+                let rotation = if p.rotation.abs() > f64::EPSILON {
+                    format!(" {:.1}", p.rotation)
+                } else {
+                    String::new()
+                };
+                let (size, extra) = match &p.shape {
+                    PadShape::Polygon(points) => {
+                        let pts: Vec<String> = points
+                            .iter()
+                            .map(|p| format!("(xy {:.3} {:.3})", p.x, p.y))
+                            .collect();
+                        (
+                                    Size::new(ANCHOR_SIZE, ANCHOR_SIZE),
+                                    format!(
+                                        " (options (clearance outline) (anchor circle)) (primitives (gr_poly (pts {}) (width 0)))",
+                                        pts.join(" ")
+                                    ),
+                                )
+                    }
+                    PadShape::RoundRect(ratio) => {
+                        (p.size.clone(), format!(" (roundrect_rratio {:.3})", ratio))
+                    }
+                    PadShape::Trapezoid(dx, dy) => {
+                        (p.size.clone(), format!(" (rect_delta {:.3} {:.3})", dx, dy))
                     }
-                    Element::Pad(p) => {
-                        writeln!(
+                    _ => (p.size.clone(), String::new()),
+                };
+                writeln!(
                             f,
-                            "  (pad {name} {kind} {shape} (at {x:.3} {y:.3}) (size {sx:.3} {sy:.3}) (layers {layers}) (solder_mask_margin {mask:.3}))",
+                            "  (pad \"{name}\" {kind} {shape} (at {x:.3} {y:.3}{rotation}) (size {sx:.3} {sy:.3}){drill} (layers {layers}) (solder_mask_margin {mask:.3}){extra})",
                             name = p.name,
-                            kind = if p.is_smd() { "smd" } else { "thru_hole" },
+                            kind = p.kind(),
                             shape = p.shape,
                             x = p.origin.x,
                             y = p.origin.y,
-                            sx = p.size.x,
-                            sy = p.size.y,
-                            layers = p.layers,
+                            rotation = rotation,
+                            sx = size.x,
+                            sy = size.y,
+                            drill = drill,
+                            layers = layers,
                             mask = p.mask,
+                            extra = extra,
                         )?;
+                // Paste windows are rendered as separate paste-only pads without a name
+                for w in &p.paste_windows {
+                    writeln!(
+                                f,
+                                "  (pad \"\" smd rect (at {x:.3} {y:.3}) (size {sx:.3} {sy:.3}) (layers {layers}))",
+                                x = p.origin.x + (w.p.0.x + w.p.1.x) / 2.0,
+                                y = p.origin.y + (w.p.0.y + w.p.1.y) / 2.0,
+                                sx = w.p.1.x - w.p.0.x,
+                                sy = w.p.1.y - w.p.0.y,
+                                layers = paste,
+                            )?;
+                }
+            }
+            _ => (),
+        }
+    }
+    writeln!(f, ")")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    enum Sexp {
+        Atom(String),
+        List(Vec<Sexp>),
+    }
+
+    impl Sexp {
+        fn head(&self) -> &str {
+            match self {
+                Sexp::List(items) => match items.first() {
+                    Some(Sexp::Atom(a)) => a,
+                    _ => "",
+                },
+                Sexp::Atom(_) => "",
+            }
+        }
+    }
+
+    // Parse a single s-expression, panicking on unbalanced brackets or quotes
+    fn parse(s: &str) -> Sexp {
+        fn list(chars: &mut std::iter::Peekable<std::str::Chars>) -> Vec<Sexp> {
+            let mut items = Vec::new();
+            while let Some(&c) = chars.peek() {
+                match c {
+                    '(' => {
+                        chars.next();
+                        items.push(Sexp::List(list(chars)));
+                    }
+                    ')' => {
+                        chars.next();
+                        return items;
+                    }
+                    '"' => {
+                        chars.next();
+                        let atom: String = chars.by_ref().take_while(|&c| c != '"').collect();
+                        items.push(Sexp::Atom(atom));
+                    }
+                    c if c.is_whitespace() => {
+                        chars.next();
+                    }
+                    _ => {
+                        let mut atom = String::new();
+                        while let Some(&c) = chars.peek() {
+                            if c.is_whitespace() || c == '(' || c == ')' || c == '"' {
+                                break;
+                            }
+                            atom.push(c);
+                            chars.next();
+                        }
+                        items.push(Sexp::Atom(atom));
                     }
-                    _ => (),
                 }
             }
-            writeln!(f, ")")?;
+            panic!("unbalanced s-expression");
         }
-        Ok(())
+        assert_eq!(s.matches('"').count() % 2, 0);
+        let mut chars = s.trim().chars().peekable();
+        assert_eq!(chars.next(), Some('('));
+        let sexp = Sexp::List(list(&mut chars));
+        assert_eq!(chars.next(), None);
+        sexp
     }
 
-    /// Builds a `KicadFootprints` with applied settings from `Config`.
-    pub fn settings(self, _lib_cfg: &Config) -> Self {
-        // TODO: Use settings
-        self
+    fn render(drawing: &Drawing) -> String {
+        let mut out = Vec::new();
+        write_footprint(&mut out, "TEST", drawing).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn keep_out() {
+        let mut drawing = Drawing::new();
+        drawing.add_keep_out(
+            KeepOut::new(-9.0, -12.75, 9.0, -6.75).layer(Layer::COPPER_TOP | Layer::COPPER_BOTTOM),
+        );
+        let out = render(&drawing);
+        assert!(!out.contains("zone"));
+        let module = parse(&out);
+        assert_eq!(module.head(), "module");
+        let children = match &module {
+            Sexp::List(items) => &items[3..],
+            _ => unreachable!(),
+        };
+        for child in children {
+            assert!(
+                ["layer", "fp_text", "fp_line", "fp_circle", "fp_poly", "pad"]
+                    .contains(&child.head())
+            );
+        }
+        assert_eq!(out.matches("(layer Dwgs.User)").count(), 7);
+        assert!(
+            out.contains("(fp_line (start -9.000 -12.750) (end 9.000 -6.750) (layer Dwgs.User)")
+        );
+        assert!(out.contains("(fp_text user KEEP-OUT (at 0.000 -9.750) (layer Dwgs.User)"));
+    }
+
+    fn pad(pad: Pad) -> String {
+        let mut drawing = Drawing::new();
+        drawing.add_pad(pad);
+        let out = render(&drawing);
+        parse(&out);
+        out.lines()
+            .filter(|l| l.trim_start().starts_with("(pad"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn pad_shapes() {
+        let smd = Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP;
+        let base = Pad::new("A1")
+            .shape(PadShape::Rect)
+            .origin(1.0, -0.5)
+            .size(1.2, 0.6)
+            .layers(smd);
+        assert_eq!(
+            pad(base.clone()),
+            "  (pad \"A1\" smd rect (at 1.000 -0.500) (size 1.200 0.600) (layers F.Cu F.Mask F.Paste) (solder_mask_margin 0.000))"
+        );
+        assert_eq!(
+            pad(base.clone().shape(PadShape::Oval).rotation(90.0)),
+            "  (pad \"A1\" smd oval (at 1.000 -0.500 90.0) (size 1.200 0.600) (layers F.Cu F.Mask F.Paste) (solder_mask_margin 0.000))"
+        );
+        assert_eq!(
+            pad(base.clone().shape(PadShape::RoundRect(0.25))),
+            "  (pad \"A1\" smd roundrect (at 1.000 -0.500) (size 1.200 0.600) (layers F.Cu F.Mask F.Paste) (solder_mask_margin 0.000) (roundrect_rratio 0.250))"
+        );
+        assert_eq!(
+            pad(base.clone().shape(PadShape::Trapezoid(0.2, 0.0))),
+            "  (pad \"A1\" smd trapezoid (at 1.000 -0.500) (size 1.200 0.600) (layers F.Cu F.Mask F.Paste) (solder_mask_margin 0.000) (rect_delta 0.200 0.000))"
+        );
+        let triangle = vec![
+            Point::new(-0.5, 0.5),
+            Point::new(0.5, 0.5),
+            Point::new(0.0, -0.5),
+        ];
+        assert_eq!(
+            pad(base.clone().shape(PadShape::Polygon(triangle))),
+            "  (pad \"A1\" smd custom (at 1.000 -0.500) (size 0.100 0.100) (layers F.Cu F.Mask F.Paste) (solder_mask_margin 0.000) \
+             (options (clearance outline) (anchor circle)) \
+             (primitives (gr_poly (pts (xy -0.500 0.500) (xy 0.500 0.500) (xy 0.000 -0.500)) (width 0))))"
+        );
+        assert_eq!(
+            pad(Pad::new("1").via(0.3, 0.15).origin(0.0, 0.0)),
+            "  (pad \"1\" thru_hole circle (at 0.000 0.000) (size 0.600 0.600) (drill 0.300) (layers *.Cu) (solder_mask_margin 0.000))"
+        );
+        assert_eq!(
+            pad(Pad::new("").non_plated(1.0).hole(1.0, 2.0)),
+            "  (pad \"\" np_thru_hole circle (at 0.000 0.000) (size 1.000 1.000) (drill oval 1.000 2.000) (layers *.Mask) (solder_mask_margin 0.000))"
+        );
+    }
+
+    #[test]
+    fn paste_windows() {
+        let layers = Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP;
+        let windows = vec![
+            Rect::new(-1.0, -1.0, 0.0, 0.0),
+            Rect::new(0.0, 0.0, 1.0, 1.0),
+        ];
+        let out = pad(Pad::new("EP")
+            .shape(PadShape::Rect)
+            .origin(0.0, 1.0)
+            .size(2.4, 2.4)
+            .layers(layers)
+            .paste_windows(windows));
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("  (pad \"EP\" smd rect"));
+        assert!(lines[0].contains("(layers F.Cu F.Mask)"));
+        assert_eq!(
+            lines[1],
+            "  (pad \"\" smd rect (at -0.500 0.500) (size 1.000 1.000) (layers F.Paste))"
+        );
+        assert_eq!(
+            lines[2],
+            "  (pad \"\" smd rect (at 0.500 1.500) (size 1.000 1.000) (layers F.Paste))"
+        );
     }
 }
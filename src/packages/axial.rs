@@ -0,0 +1,54 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::Axial;
+
+use super::PackageHandler;
+
+pub struct AxialPackage {}
+
+impl AxialPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for AxialPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw axial pattern");
+
+        let lead_diameter = super::lead_diameter(comp_cfg)?;
+        let body_len = comp_cfg.get_range("package.body-length")?;
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+        let pitch = match comp_cfg.get_f64("package.pitch") {
+            Ok(pitch) => pitch,
+            Err(_) => {
+                // Leads are bent right at the body ends
+                let bend_radius = comp_cfg
+                    .get_f64("package.bend-radius")
+                    .unwrap_or(lead_diameter);
+                let pitch = body_len.max() + 2.0 * bend_radius + lead_diameter;
+                (pitch / 0.1 - 1e-6).ceil() * 0.1
+            }
+        };
+
+        let mut axial = Axial::default()
+            .pad(super::tht_pad(comp_cfg, lib_cfg, lead_diameter))
+            .pitch(pitch)
+            .body(body_len.nom(), body_diameter.nom())
+            .polarized(comp_cfg.get_bool("package.polarized").unwrap_or(false));
+        if let Some(mark) = super::polarity_mark(comp_cfg)? {
+            axial = axial.polarity_mark(mark);
+        }
+
+        let mut drawing = Drawing::new();
+        axial.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw axial model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
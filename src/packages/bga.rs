@@ -0,0 +1,119 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::{GridArray, Ipc7351B};
+use crate::pinout::Pinout;
+
+use super::PackageHandler;
+
+pub struct BgaPackage {}
+
+impl BgaPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for BgaPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw BGA pattern");
+
+        let pitch = comp_cfg.get_pair("package.pitch")?;
+        let row_count = comp_cfg.get_u64("package.row-count")? as usize;
+        let column_count = comp_cfg
+            .get_u64("package.column-count")
+            .unwrap_or(row_count as u64) as usize;
+        let ball_diameter = comp_cfg.get_range("package.lead-diameter")?;
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg
+            .get_range("package.body-size-y")
+            .or_else(|_| comp_cfg.get_range("package.body-size-x"))?;
+
+        let pinout = Pinout::new();
+        let mut depopulated = Vec::new();
+        if let Ok(value) = comp_cfg.get_element("package.depopulated") {
+            depopulated.append(&mut pinout.parse_number(value)?);
+        }
+        // Missing balls in the center of the grid
+        if let Ok(center) = comp_cfg.get_pair("package.depopulated-center") {
+            let rows = center.0.round() as usize;
+            let columns = center.1.round() as usize;
+            let row_begin = (row_count - rows.min(row_count)) / 2;
+            let column_begin = (column_count - columns.min(column_count)) / 2;
+            for row in row_begin..(row_begin + rows) {
+                for col in column_begin..(column_begin + columns) {
+                    let row_name = pinout.row_name(row).unwrap_or_default();
+                    depopulated.push(format!("{}{}", row_name, col + 1));
+                }
+            }
+        }
+
+        let always_calc = lib_cfg.get_bool("pattern.always-calculate").unwrap();
+        let mut land = Ipc7351B::ball_land(ball_diameter.nom());
+        if !always_calc {
+            if let Ok(pad_diameter) = comp_cfg.get_f64("pattern.pad-diameter") {
+                land = pad_diameter;
+            }
+        }
+        let ball_pad = comp_cfg
+            .get_str("pattern.ball-pad")
+            .or_else(|_| lib_cfg.get_str("pattern.ball-pad"))?;
+        let mask_defined = ball_pad.eq_ignore_ascii_case("smd");
+
+        let grid_array = GridArray::default()
+            .pitch(pitch.0, pitch.1)
+            .counts(row_count, column_count)
+            .land(land, mask_defined)
+            .depopulated(depopulated)
+            .body(body_size_x.nom(), body_size_y.nom())
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+
+        let mut drawing = Drawing::new();
+        grid_array.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw BGA model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::Element;
+
+    #[test]
+    fn depopulated() -> Result<()> {
+        let comp_cfg = Config::from_yaml(
+            r"
+            package:
+              pitch: 0.8
+              row-count: 6
+              lead-diameter: 0.4
+              body-size-x: 6
+              depopulated: [A1..A2]
+              depopulated-center: 2;2
+            ",
+        )?;
+        let drawing = BgaPackage::new().draw_pattern(&comp_cfg, &load_config!("../qeda.yml"))?;
+        let names: Vec<_> = drawing
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad.name.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(names.len(), 6 * 6 - 2 - 4);
+        for name in ["A1", "A2", "C3", "C4", "D3", "D4"] {
+            assert!(!names.contains(&name));
+        }
+        for name in ["A3", "B2", "C2", "C5", "E3", "F6"] {
+            assert!(names.contains(&name));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::{Cae, Ipc7351B};
+
+use super::{PackageHandler, PackageType};
+
+pub struct CaePackage {}
+
+impl CaePackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for CaePackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw CAE pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg
+            .get_range("package.body-size-y")
+            .unwrap_or(body_size_x);
+        let body_width = body_size_x.nom();
+        let body_height = body_size_y.nom();
+        let chamfer = comp_cfg
+            .get_f64("package.chamfer")
+            .unwrap_or(body_width.min(body_height) / 4.0);
+
+        let pad_props = Ipc7351B::new(PackageType::Cae)
+            .lead_span(comp_cfg.get_range("package.lead-span")?)
+            .lead_len(comp_cfg.get_range("package.lead-length")?)
+            .lead_width(comp_cfg.get_range("package.lead-width")?)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let mut cae =
+            Cae::default()
+                .pad_properties(pad_props)
+                .body(body_width, body_height, chamfer);
+        if let Some(mark) = super::polarity_mark(comp_cfg)? {
+            cae = cae.polarity_mark(mark);
+        }
+
+        let mut drawing = Drawing::new();
+        cae.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw CAE model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
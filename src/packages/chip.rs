@@ -41,9 +41,13 @@ impl PackageHandler for ChipPackage {
             .calc()
             .post_proc(comp_cfg, lib_cfg);
 
-        let two_pin = TwoPin::default()
+        let mut two_pin = TwoPin::default()
             .pad_properties(pad_props)
-            .body(body_width, body_height);
+            .body(body_width, body_height)
+            .polarized(comp_cfg.get_bool("package.polarized").unwrap_or(false));
+        if let Some(mark) = super::polarity_mark(comp_cfg)? {
+            two_pin = two_pin.polarity_mark(mark);
+        }
 
         let mut drawing = Drawing::new();
         two_pin.draw(&mut drawing, lib_cfg);
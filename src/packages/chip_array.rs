@@ -0,0 +1,66 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::{Ipc7351B, TwoPin};
+
+use super::{PackageHandler, PackageType};
+
+pub struct ChipArrayPackage {}
+
+impl ChipArrayPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for ChipArrayPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw chip array pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let lead_count = comp_cfg.get_u64("package.lead-count")?;
+        ensure!(
+            lead_count > 0 && lead_count % 2 == 0,
+            QedaError::InvalidLeadCount(lead_count, "it should be even")
+        );
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let package_type = match comp_cfg.get_str("package.termination").unwrap_or("convex") {
+            "convex" => PackageType::ConvexArray,
+            "concave" => PackageType::ConcaveArray,
+            _ => bail!(QedaError::InvalidElementType(
+                "package.termination".to_string(),
+                "'convex' or 'concave'"
+            )),
+        };
+
+        let pad_props = Ipc7351B::new(package_type)
+            .pitch(pitch)
+            .lead_span(
+                comp_cfg
+                    .get_range("package.lead-span")
+                    .unwrap_or(body_size_x),
+            )
+            .lead_len(comp_cfg.get_range("package.lead-length")?)
+            .lead_width(comp_cfg.get_range("package.lead-width")?)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let two_pin = TwoPin::default()
+            .pad_properties(pad_props)
+            .pairs(lead_count as usize / 2, pitch)
+            .body(body_size_x.nom(), body_size_y.nom())
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+
+        let mut drawing = Drawing::new();
+        two_pin.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw chip array model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
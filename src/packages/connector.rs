@@ -0,0 +1,104 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Layer, Pad, PadShape};
+use crate::error::*;
+use crate::pattern::{self, Header, Ipc7351B};
+
+use super::{PackageHandler, PackageType};
+
+pub struct ConnectorPackage {}
+
+impl ConnectorPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for ConnectorPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw connector pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let lead_count = comp_cfg.get_u64("package.lead-count")?;
+        let row_count = comp_cfg.get_u64("package.row-count").unwrap_or(1);
+        ensure!(
+            lead_count > 0 && (row_count == 1 || (row_count == 2 && lead_count % 2 == 0)),
+            QedaError::InvalidLeadCount(
+                lead_count,
+                "it should be even for a dual row connector ('row-count' is 1 or 2)"
+            )
+        );
+        let row_pitch = comp_cfg.get_f64("package.row-pitch").unwrap_or(pitch);
+        let row_len = lead_count / row_count;
+        let smd = comp_cfg.get_str("package.mount").unwrap_or("tht") == "smd";
+
+        let (pad, row_span) = if smd {
+            let pad_props = Ipc7351B::new(PackageType::GullWing)
+                .pitch(if row_count == 1 { 2.0 * pitch } else { pitch })
+                .lead_span(comp_cfg.get_range("package.lead-span")?)
+                .lead_len(comp_cfg.get_range("package.lead-length")?)
+                .lead_width(comp_cfg.get_range("package.lead-width")?)
+                .settings(lib_cfg)
+                .calc()
+                .post_proc(comp_cfg, lib_cfg);
+            let pad = Pad::new("")
+                .shape(PadShape::Rect)
+                .size(pad_props.size.x, pad_props.size.y)
+                .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP);
+            (pad, pad_props.distance)
+        } else {
+            let lead_diameter = super::lead_diameter(comp_cfg)?;
+            let row_span = if row_count > 1 { row_pitch } else { 0.0 };
+            (super::tht_pad(comp_cfg, lib_cfg, lead_diameter), row_span)
+        };
+
+        let body_width = comp_cfg
+            .get_range("package.body-size-x")
+            .map(|r| r.nom())
+            .unwrap_or(row_count as f64 * row_pitch);
+        let body_height = comp_cfg
+            .get_range("package.body-size-y")
+            .map(|r| r.nom())
+            .unwrap_or(row_len as f64 * pitch);
+
+        let mut header = Header::default()
+            .pad(pad)
+            .pitch(pitch)
+            .row_span(row_span)
+            .counts(lead_count as usize, row_count as usize)
+            .row_by_row(comp_cfg.get_str("package.numbering").unwrap_or("zigzag") == "row-by-row")
+            .pegs(pegs(comp_cfg, lib_cfg)?)
+            .body(body_width, body_height)
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+        if comp_cfg.get_bool("package.shrouded").unwrap_or(false) {
+            let wall = comp_cfg.get_f64("package.wall-thickness").unwrap_or(1.0);
+            let key_width = comp_cfg.get_f64("package.key-width").unwrap_or(2.0 * pitch);
+            header = header.shroud(wall, key_width);
+        }
+
+        let mut drawing = Drawing::new();
+        header.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw connector model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
+
+// Create non-plated holes for mounting pegs at `package.pegs` positions
+fn pegs(comp_cfg: &Config, lib_cfg: &Config) -> Result<Vec<Pad>> {
+    let positions = match comp_cfg.get_element("package.pegs") {
+        Ok(positions) => positions,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let diameter = comp_cfg.get_range("package.peg-diameter")?.max();
+    let (hole, _) = pattern::drill(diameter, lib_cfg);
+    let peg = Pad::new("").non_plated(hole);
+
+    Ok(super::parse_pairs("package.pegs", positions)?
+        .into_iter()
+        .map(|(x, y)| peg.clone().origin(x, y))
+        .collect())
+}
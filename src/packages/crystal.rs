@@ -0,0 +1,128 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::{Axial, DualRow, Ipc7351B};
+
+use super::{Leads, PackageHandler, PackageType};
+
+pub struct CrystalPackage {}
+
+impl CrystalPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for CrystalPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw crystal pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_width = body_size_x.nom();
+        let body_height = body_size_y.nom();
+
+        let mut drawing = Drawing::new();
+        if comp_cfg.get_str("package.mount").unwrap_or("smd") == "tht" {
+            // HC-49 like can
+            let lead_diameter = super::lead_diameter(comp_cfg)?;
+            let axial = Axial::default()
+                .pad(super::tht_pad(comp_cfg, lib_cfg, lead_diameter))
+                .pitch(comp_cfg.get_f64("package.pitch")?)
+                .body(body_width, body_height)
+                .rounded(true);
+            axial.draw(&mut drawing, lib_cfg);
+            return Ok(drawing);
+        }
+
+        let lead_count = comp_cfg.get_u64("package.lead-count").unwrap_or(4);
+        let numbering = comp_cfg.get_str("package.numbering").unwrap_or("diagonal");
+        let (left, right) = leads(lead_count, numbering)?;
+        // Two-pad parts have no neighbours in a row, so the pitch does not limit the pads
+        let pitch = if lead_count > 2 {
+            Some(comp_cfg.get_f64("package.pitch")?)
+        } else {
+            None
+        };
+        let lead_span = comp_cfg
+            .get_range("package.lead-span")
+            .unwrap_or(body_size_x);
+
+        let mut ipc = Ipc7351B::new(PackageType::FlatNoLead);
+        if let Some(pitch) = pitch {
+            ipc = ipc.pitch(pitch);
+        }
+        let pad_props = ipc
+            .lead_span(lead_span)
+            .lead_len(comp_cfg.get_range("package.lead-length")?)
+            .lead_width(comp_cfg.get_range("package.lead-width")?)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let dual_row = DualRow::default()
+            .pad_properties(pad_props)
+            .pitch(pitch.unwrap_or_default())
+            .lead_layout(left, right)
+            .body(body_width, body_height)
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+        dual_row.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw crystal model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
+
+// Get left and right pad rows (from top to bottom) according to the numbering convention.
+//
+// Diagonal numbering (crystals) goes counterclockwise from the bottom left pad, so crystal
+// terminals 1 and 3 are diagonal. Sequential numbering (oscillators) goes counterclockwise
+// from the top left pad like in ICs.
+fn leads(lead_count: u64, numbering: &str) -> Result<(Leads, Leads)> {
+    let leads =
+        |numbers: &[u64]| -> Leads { numbers.iter().map(|n| Some(n.to_string())).collect() };
+    Ok(match (lead_count, numbering) {
+        (2, _) => (leads(&[1]), leads(&[2])),
+        (4, "diagonal") => (leads(&[4, 1]), leads(&[3, 2])),
+        (4, "sequential") => (leads(&[1, 2]), leads(&[4, 3])),
+        (4, _) => bail!(QedaError::InvalidElementType(
+            "package.numbering".to_string(),
+            "'diagonal' or 'sequential'"
+        )),
+        _ => bail!(QedaError::InvalidLeadCount(
+            lead_count,
+            "it should be 2 or 4"
+        )),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(leads: &Leads) -> Vec<&str> {
+        leads.iter().map(|n| n.as_deref().unwrap()).collect()
+    }
+
+    #[test]
+    fn numbering() {
+        let (left, right) = leads(4, "diagonal").unwrap();
+        assert_eq!(names(&left), ["4", "1"]);
+        assert_eq!(names(&right), ["3", "2"]);
+
+        let (left, right) = leads(4, "sequential").unwrap();
+        assert_eq!(names(&left), ["1", "2"]);
+        assert_eq!(names(&right), ["4", "3"]);
+
+        let (left, right) = leads(2, "diagonal").unwrap();
+        assert_eq!(names(&left), ["1"]);
+        assert_eq!(names(&right), ["2"]);
+
+        assert!(leads(4, "zigzag").is_err());
+        assert!(leads(3, "diagonal").is_err());
+    }
+}
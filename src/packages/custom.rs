@@ -0,0 +1,163 @@
+use serde_json::Value;
+
+use crate::config::Config;
+use crate::drawing::{Drawing, Layer, Pad, PadShape, Point};
+use crate::error::*;
+use crate::pattern::Custom;
+
+use super::PackageHandler;
+
+pub struct CustomPackage {}
+
+impl CustomPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for CustomPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw custom pattern");
+
+        let values = comp_cfg
+            .get_element("pattern.pads")?
+            .as_array()
+            .ok_or_else(|| QedaError::InvalidElementType("pattern.pads".to_string(), "array"))?;
+        let pads = values.iter().map(pad).collect::<Result<Vec<Pad>>>()?;
+
+        let mut custom = Custom::default().pads(pads);
+        if let Ok(courtyard) = comp_cfg.get_f64("pattern.courtyard") {
+            custom = custom.courtyard(courtyard);
+        }
+        if let (Ok(body_size_x), Ok(body_size_y)) = (
+            comp_cfg.get_range("package.body-size-x"),
+            comp_cfg.get_range("package.body-size-y"),
+        ) {
+            custom = custom.body(body_size_x.nom(), body_size_y.nom());
+        }
+
+        let mut drawing = Drawing::new();
+        custom.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw custom model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
+
+// Parse polygon vertices: an array of pairs
+fn points(value: Option<&Value>) -> Result<Vec<Point>> {
+    let key = "pattern.pads.points";
+    let error = || QedaError::InvalidElementType(key.to_string(), "array of 3 pairs at least");
+    let points: Vec<_> = super::parse_pairs(key, value.ok_or_else(error)?)?
+        .into_iter()
+        .map(|(x, y)| Point::new(x, y))
+        .collect();
+    ensure!(points.len() >= 3, error());
+    Ok(points)
+}
+
+// Parse a pad description like
+// `{ name: 1, shape: rect, size: [1, 0.5], position: [-2, 0], rotation: 90, hole: 0.3,
+// layers: [copper-top, mask-top] }`, trapezoid pads have `delta`, polygon ones have `points`
+// instead of `size`
+fn pad(value: &Value) -> Result<Pad> {
+    let error =
+        |key: &str, kind| QedaError::InvalidElementType(format!("pattern.pads.{}", key), kind);
+    let object = value.as_object().ok_or_else(|| error("", "object"))?;
+    let pair = |key: &str| -> Result<Option<(f64, f64)>> {
+        match object.get(key) {
+            None => Ok(None),
+            Some(Value::Number(n)) => {
+                let v = n.as_f64().unwrap_or_default();
+                Ok(Some((v, v)))
+            }
+            Some(v) => v
+                .as_array()
+                .filter(|a| a.len() == 2)
+                .and_then(|a| Some((a[0].as_f64()?, a[1].as_f64()?)))
+                .map(Some)
+                .ok_or_else(|| error(key, "number or pair").into()),
+        }
+    };
+
+    let name = match object.get("name") {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Number(n)) => n.to_string(),
+        None => String::new(),
+        _ => bail!(error("name", "string")),
+    };
+    let position = pair("position")?.ok_or_else(|| error("position", "number or pair"))?;
+    let hole = pair("hole")?;
+    let shape = match object.get("shape").and_then(Value::as_str) {
+        Some("circle") => PadShape::Circle,
+        Some("oval") => PadShape::Oval,
+        Some("polygon") => PadShape::Polygon(points(object.get("points"))?),
+        Some("rect") | None => PadShape::Rect,
+        Some("roundrect") => PadShape::RoundRect(
+            object
+                .get("corner-ratio")
+                .and_then(Value::as_f64)
+                .unwrap_or_default(),
+        ),
+        Some("trapezoid") => {
+            let delta = pair("delta")?.ok_or_else(|| error("delta", "pair"))?;
+            PadShape::Trapezoid(delta.0, delta.1)
+        }
+        _ => bail!(error(
+            "shape",
+            "'circle', 'oval', 'polygon', 'rect', 'roundrect' or 'trapezoid'"
+        )),
+    };
+    // Size of a polygon pad is its bounding box around the origin
+    let size = match &shape {
+        PadShape::Polygon(points) => points.iter().fold((0.0, 0.0), |(x, y), p| {
+            (f64::max(x, 2.0 * p.x.abs()), f64::max(y, 2.0 * p.y.abs()))
+        }),
+        _ => pair("size")?.ok_or_else(|| error("size", "number or pair"))?,
+    };
+    let rotation = match object.get("rotation") {
+        Some(v) => v.as_f64().ok_or_else(|| error("rotation", "number"))?,
+        None => 0.0,
+    };
+    let layers = match object.get("layers") {
+        Some(v) => {
+            let names = v.as_array().ok_or_else(|| error("layers", "array"))?;
+            let mut layers = Layer::NONE;
+            for name in names {
+                layers |= match name.as_str() {
+                    Some("top") => Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP,
+                    Some("bottom") => {
+                        Layer::COPPER_BOTTOM | Layer::MASK_BOTTOM | Layer::PASTE_BOTTOM
+                    }
+                    Some("copper-top") => Layer::COPPER_TOP,
+                    Some("copper-bottom") => Layer::COPPER_BOTTOM,
+                    Some("mask-top") => Layer::MASK_TOP,
+                    Some("mask-bottom") => Layer::MASK_BOTTOM,
+                    Some("paste-top") => Layer::PASTE_TOP,
+                    Some("paste-bottom") => Layer::PASTE_BOTTOM,
+                    _ => bail!(error("layers", "array of layer names")),
+                };
+            }
+            layers
+        }
+        None if hole.is_some() => {
+            Layer::COPPER_TOP | Layer::COPPER_BOTTOM | Layer::MASK_TOP | Layer::MASK_BOTTOM
+        }
+        None => Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP,
+    };
+
+    let mut pad = Pad::new(&name)
+        .shape(shape)
+        .size(size.0, size.1)
+        .origin(position.0, position.1)
+        .rotation(rotation)
+        .layers(layers);
+    if let Some(hole) = hole {
+        pad = pad.hole(hole.0, hole.1);
+    }
+    Ok(pad)
+}
@@ -0,0 +1,51 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::DualInline;
+
+use super::PackageHandler;
+
+pub struct DipPackage {}
+
+impl DipPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for DipPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw DIP pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let lead_count = comp_cfg.get_u64("package.lead-count")?;
+        ensure!(
+            lead_count > 0 && lead_count % 2 == 0,
+            QedaError::InvalidLeadCount(lead_count, "it should be even")
+        );
+        let row_span = comp_cfg.get_f64("package.row-span")?;
+        let lead_diameter = super::lead_diameter(comp_cfg)?;
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+
+        let pad = super::tht_pad(comp_cfg, lib_cfg, lead_diameter);
+
+        let dual_inline = DualInline::default()
+            .pad(pad)
+            .pitch(pitch)
+            .row_span(row_span)
+            .lead_count(lead_count as usize)
+            .body(body_size_x.nom(), body_size_y.nom())
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+
+        let mut drawing = Drawing::new();
+        dual_inline.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw DIP model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Layer, Pad, PadShape};
+use crate::error::*;
+use crate::pattern::SinglePad;
+
+use super::PackageHandler;
+
+pub struct FiducialPackage {}
+
+impl FiducialPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for FiducialPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw fiducial pattern");
+
+        // Local fiducials are smaller than global (board) ones by default
+        let default_diameter = match comp_cfg.get_str("package.fiducial").unwrap_or("global") {
+            "local" => 0.5,
+            _ => 1.0,
+        };
+        let diameter = comp_cfg
+            .get_f64("package.pad-diameter")
+            .unwrap_or(default_diameter);
+        let mask_diameter = comp_cfg
+            .get_f64("package.mask-diameter")
+            .unwrap_or(2.0 * diameter);
+        let keep_out = comp_cfg
+            .get_f64("package.keep-out-diameter")
+            .unwrap_or(3.0 * diameter);
+
+        let pad = Pad::new("")
+            .shape(PadShape::Circle)
+            .size(diameter, diameter)
+            .layers(Layer::COPPER_TOP | Layer::MASK_TOP);
+        let single_pad = SinglePad::default()
+            .pad(pad)
+            .mask_opening(mask_diameter)
+            .keep_out(keep_out);
+
+        let mut drawing = Drawing::new();
+        single_pad.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw fiducial model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::Element;
+
+    #[test]
+    fn global() -> Result<()> {
+        let comp_cfg = Config::from_yaml("package: {}")?;
+        let drawing =
+            FiducialPackage::new().draw_pattern(&comp_cfg, &load_config!("../qeda.yml"))?;
+        let pads: Vec<_> = drawing
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect();
+
+        // Bare copper without paste inside a mask opening of two pad diameters
+        assert_eq!(pads.len(), 1);
+        assert_eq!((pads[0].size.x, pads[0].size.y), (1.0, 1.0));
+        assert_eq!(pads[0].layers, Layer::COPPER_TOP | Layer::MASK_TOP);
+        assert_eq!(pads[0].mask, 0.5);
+
+        // Keep-out of three pad diameters on courtyard
+        assert!(drawing.elements.iter().any(|e| matches!(
+            e,
+            Element::Circle(c) if c.radius == 1.5 && c.layer == Layer::COURTYARD_TOP
+        )));
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::{Ipc7351B, TwoPin};
+
+use super::{PackageHandler, PackageType};
+
+pub struct MelfPackage {}
+
+impl MelfPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for MelfPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw MELF pattern");
+
+        let body_length = comp_cfg.get_range("package.body-length")?;
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+
+        let pad_props = Ipc7351B::new(PackageType::Melf)
+            .lead_span(body_length)
+            .lead_len(comp_cfg.get_range("package.lead-length")?)
+            .lead_width(body_diameter)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let mut two_pin = TwoPin::default()
+            .pad_properties(pad_props)
+            .body(body_length.nom(), body_diameter.nom())
+            .polarized(comp_cfg.get_bool("package.polarized").unwrap_or(false));
+        if let Some(mark) = super::polarity_mark(comp_cfg)? {
+            two_pin = two_pin.polarity_mark(mark);
+        }
+
+        let mut drawing = Drawing::new();
+        two_pin.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw MELF model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
@@ -1,21 +1,87 @@
+mod axial;
+mod bga;
+mod cae;
 mod chip;
+mod chip_array;
+mod connector;
+mod crystal;
+mod custom;
+mod dip;
+mod fiducial;
+mod melf;
+mod module;
+mod molded;
+mod mounting_hole;
+mod pak;
+mod plcc;
+mod qfn;
+mod qfp;
+mod radial;
+mod soj;
+mod son;
 mod sop;
+mod sot;
+mod test_point;
+mod to_cylindrical;
+mod to_flange;
 
 use std::collections::HashMap;
 use std::fmt::{self, Debug};
+use std::str::FromStr;
 
-use crate::config::Config;
-use crate::drawing::Drawing;
+use serde_json::Value;
+
+use crate::config::{Config, Range};
+use crate::drawing::{Drawing, Layer, Pad, PadShape};
 use crate::error::*;
+use crate::pattern;
 
+use axial::AxialPackage;
+use bga::BgaPackage;
+use cae::CaePackage;
 use chip::ChipPackage;
+use chip_array::ChipArrayPackage;
+use connector::ConnectorPackage;
+use crystal::CrystalPackage;
+use custom::CustomPackage;
+use dip::DipPackage;
+use fiducial::FiducialPackage;
+use melf::MelfPackage;
+use module::ModulePackage;
+use molded::MoldedPackage;
+use mounting_hole::MountingHolePackage;
+use pak::PakPackage;
+use plcc::PlccPackage;
+use qfn::QfnPackage;
+use qfp::QfpPackage;
+use radial::RadialPackage;
+use soj::SojPackage;
+use son::SonPackage;
 use sop::SopPackage;
+use sot::SotPackage;
+use test_point::TestPointPackage;
+use to_cylindrical::ToCylindricalPackage;
+use to_flange::ToFlangePackage;
 
-#[derive(Debug)]
+/// Pad names of a lead row from top to bottom, `None` stands for a missing lead.
+type Leads = Vec<Option<String>>;
+
+#[derive(Clone, Copy, Debug)]
 pub enum PackageType {
     Unknown,
+    Bga,
+    Cae,
     Chip,
-    // TODO: Add GullWing
+    ConcaveArray,
+    ConvexArray,
+    FlatLug,
+    FlatNoLead,
+    GullWing,
+    JLead,
+    Melf,
+    Molded,
+    PullBack,
+    ThroughHole,
 }
 
 impl Default for PackageType {
@@ -25,6 +91,26 @@ impl Default for PackageType {
     }
 }
 
+impl FromStr for PackageType {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "cae" => PackageType::Cae,
+            "chip" => PackageType::Chip,
+            "concave-array" => PackageType::ConcaveArray,
+            "convex-array" => PackageType::ConvexArray,
+            "flat-lug" => PackageType::FlatLug,
+            "flat-no-lead" => PackageType::FlatNoLead,
+            "gull-wing" => PackageType::GullWing,
+            "j-lead" => PackageType::JLead,
+            "melf" => PackageType::Melf,
+            "molded" => PackageType::Molded,
+            "pull-back" => PackageType::PullBack,
+            _ => bail!(QedaError::InvalidPackageType(s.to_string())),
+        })
+    }
+}
+
 pub trait PackageHandler {
     fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing>;
     fn draw_model(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing>;
@@ -45,8 +131,47 @@ impl Packages {
     /// Creates an empty `Packages`.
     pub fn new() -> Self {
         let mut handlers: HashMap<&'static str, Box<dyn PackageHandler>> = HashMap::new();
+        handlers.insert("axial", Box::new(AxialPackage::new()));
+        handlers.insert("bga", Box::new(BgaPackage::new()));
+        handlers.insert("cae", Box::new(CaePackage::new()));
         handlers.insert("chip", Box::new(ChipPackage::new()));
+        handlers.insert("chip-array", Box::new(ChipArrayPackage::new()));
+        handlers.insert("connector", Box::new(ConnectorPackage::new()));
+        handlers.insert("crystal", Box::new(CrystalPackage::new()));
+        handlers.insert("custom", Box::new(CustomPackage::new()));
+        handlers.insert("dfn", Box::new(SonPackage::new()));
+        handlers.insert("dip", Box::new(DipPackage::new()));
+        handlers.insert(
+            "dpak",
+            Box::new(PakPackage::new(PackageType::GullWing, PackageType::FlatLug)),
+        );
+        handlers.insert("fiducial", Box::new(FiducialPackage::new()));
+        handlers.insert("melf", Box::new(MelfPackage::new()));
+        handlers.insert("module", Box::new(ModulePackage::new()));
+        handlers.insert("molded", Box::new(MoldedPackage::new()));
+        handlers.insert("mounting-hole", Box::new(MountingHolePackage::new()));
+        handlers.insert("plcc", Box::new(PlccPackage::new()));
+        handlers.insert("qfn", Box::new(QfnPackage::new()));
+        handlers.insert("qfp", Box::new(QfpPackage::new()));
+        handlers.insert("radial", Box::new(RadialPackage::new()));
+        handlers.insert("soj", Box::new(SojPackage::new()));
+        handlers.insert("son", Box::new(SonPackage::new()));
         handlers.insert("sop", Box::new(SopPackage::new()));
+        handlers.insert("sot", Box::new(SotPackage::new()));
+        handlers.insert(
+            "sot223",
+            Box::new(PakPackage::new(
+                PackageType::GullWing,
+                PackageType::GullWing,
+            )),
+        );
+        handlers.insert(
+            "sot89",
+            Box::new(PakPackage::new(PackageType::FlatLug, PackageType::FlatLug)),
+        );
+        handlers.insert("test-point", Box::new(TestPointPackage::new()));
+        handlers.insert("to-cylindrical", Box::new(ToCylindricalPackage::new()));
+        handlers.insert("to-flange", Box::new(ToFlangePackage::new()));
 
         Packages { handlers }
     }
@@ -66,3 +191,156 @@ impl Default for Packages {
         Self::new()
     }
 }
+
+// Get lead counts of top/bottom rows and of left/right rows for four-sided packages
+fn quad_lead_counts(comp_cfg: &Config) -> Result<(usize, usize)> {
+    let lead_count = comp_cfg.get_u64("package.lead-count")?;
+    let (x, y) = match (
+        comp_cfg.get_u64("package.lead-count-x"),
+        comp_cfg.get_u64("package.lead-count-y"),
+    ) {
+        (Ok(x), Ok(y)) => (x, y),
+        (Ok(x), Err(_)) => (x, lead_count / 2 - x.min(lead_count / 2)),
+        (Err(_), Ok(y)) => (lead_count / 2 - y.min(lead_count / 2), y),
+        (Err(_), Err(_)) => {
+            ensure!(
+                lead_count % 4 == 0,
+                QedaError::InvalidLeadCount(lead_count, "it should be a multiple of 4")
+            );
+            (lead_count / 4, lead_count / 4)
+        }
+    };
+    ensure!(
+        x > 0 && y > 0 && 2 * (x + y) == lead_count,
+        QedaError::InvalidLeadCount(
+            lead_count,
+            "it should match 'lead-count-x' and 'lead-count-y'"
+        )
+    );
+    Ok((x as usize, y as usize))
+}
+
+// Parse a row of leads from top to bottom, where `0`, `-` or `~` stand for a missing lead
+fn parse_leads(key: &str, value: &Value) -> Result<Leads> {
+    let array = value
+        .as_array()
+        .ok_or_else(|| QedaError::InvalidElementType(key.to_string(), "array"))?;
+    let mut result = Vec::with_capacity(array.len());
+    for v in array {
+        let lead = match v {
+            Value::Number(n) => {
+                let n = n.as_f64().unwrap_or(-1.0);
+                if n == 0.0 {
+                    None
+                } else if n > 0.0 && n.fract() == 0.0 {
+                    Some((n as u64).to_string())
+                } else {
+                    bail!(QedaError::InvalidElementType(
+                        key.to_string(),
+                        "array of positive integers"
+                    ))
+                }
+            }
+            Value::String(s) if s.is_empty() || s == "-" => None,
+            Value::String(s) => Some(s.clone()),
+            Value::Null => None,
+            _ => bail!(QedaError::InvalidElementType(
+                key.to_string(),
+                "array of positive integers"
+            )),
+        };
+        result.push(lead);
+    }
+    Ok(result)
+}
+
+// Parse an array of coordinate pairs like `[[-1, 0], [1, 0]]`
+fn parse_pairs(key: &str, value: &Value) -> Result<Vec<(f64, f64)>> {
+    let error = || QedaError::InvalidElementType(key.to_string(), "array of pairs");
+    let mut result = Vec::new();
+    for pair in value.as_array().ok_or_else(error)? {
+        let xy = pair
+            .as_array()
+            .filter(|a| a.len() == 2)
+            .and_then(|a| Some((a[0].as_f64()?, a[1].as_f64()?)))
+            .ok_or_else(error)?;
+        result.push(xy);
+    }
+    Ok(result)
+}
+
+// Get the package type and the lead span along the specified axis of a flat no-lead package,
+// leads end at the body edge unless `lead-span` or `pull-back` is specified
+fn no_lead_span(comp_cfg: &Config, axis: &str, body_size: Range) -> (PackageType, Range) {
+    let lead_span = comp_cfg
+        .get_range(&format!("package.lead-span-{}", axis))
+        .or_else(|_| comp_cfg.get_range("package.lead-span"))
+        .unwrap_or(body_size);
+    if let Ok(pull_back) = comp_cfg.get_f64("package.pull-back") {
+        (
+            PackageType::PullBack,
+            Range(
+                lead_span.min() - 2.0 * pull_back,
+                lead_span.max() - 2.0 * pull_back,
+            ),
+        )
+    } else {
+        (PackageType::FlatNoLead, lead_span)
+    }
+}
+
+// Get the maximum diameter of a lead: either round or rectangular one
+fn lead_diameter(comp_cfg: &Config) -> Result<f64> {
+    if let Ok(diameter) = comp_cfg.get_range("package.lead-diameter") {
+        return Ok(diameter.max());
+    }
+    let width = comp_cfg.get_range("package.lead-width")?.max();
+    let height = comp_cfg.get_range("package.lead-height")?.max();
+    Ok((width * width + height * height).sqrt())
+}
+
+// Get a polarity mark style set by `pattern.polarity.mark` of a component
+fn polarity_mark(comp_cfg: &Config) -> Result<Option<&str>> {
+    let mark = match comp_cfg.get_str("pattern.polarity.mark") {
+        Ok(mark) => mark,
+        Err(_) => return Ok(None),
+    };
+    ensure!(
+        matches!(mark, "." | "-" | "+" | "x" | "o" | "bar" | "none"),
+        QedaError::InvalidElementType(
+            "pattern.polarity.mark".to_string(),
+            "'.', '-', '+', 'x', 'o', 'bar' or 'none'"
+        )
+    );
+    Ok(Some(mark))
+}
+
+// Get a pin 1 mark style, `pattern.polarity.mark` of a component overrides the library one
+fn pin1_mark<'a>(comp_cfg: &'a Config, lib_cfg: &'a Config) -> Result<&'a str> {
+    Ok(polarity_mark(comp_cfg)?
+        .unwrap_or_else(|| lib_cfg.get_str("pattern.polarity.mark").unwrap()))
+}
+
+// Create a template pad for a through-hole lead, `pattern.pad-size` and
+// `pattern.hole-diameter` override calculated values
+fn tht_pad(comp_cfg: &Config, lib_cfg: &Config, lead_diameter: f64) -> Pad {
+    let (hole, diameter) = pattern::drill(lead_diameter, lib_cfg);
+    let mut pad = Pad::new("")
+        .hole(hole, hole)
+        .size(diameter, diameter)
+        .layers(Layer::COPPER_TOP | Layer::COPPER_BOTTOM | Layer::MASK_TOP | Layer::MASK_BOTTOM);
+    if !lib_cfg.get_bool("pattern.always-calculate").unwrap() {
+        if let Ok(hole) = comp_cfg.get_f64("pattern.hole-diameter") {
+            pad = pad.hole(hole, hole);
+        }
+        if let Ok(size) = comp_cfg.get_pair("pattern.pad-size") {
+            pad = pad.size(size.0, size.1);
+        } else if let Ok(diameter) = comp_cfg.get_f64("pattern.pad-diameter") {
+            pad = pad.size(diameter, diameter);
+        }
+    }
+    if (pad.size.x - pad.size.y).abs() > f64::EPSILON {
+        pad = pad.shape(PadShape::Oval);
+    }
+    pad
+}
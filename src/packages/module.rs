@@ -0,0 +1,117 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Pad, Size};
+use crate::error::*;
+use crate::pattern::{self, Ipc7351B, Module};
+
+use super::{PackageHandler, PackageType};
+
+pub struct ModulePackage {}
+
+impl ModulePackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for ModulePackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw module pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let lead_counts = lead_counts(comp_cfg)?;
+        let lead_count: usize = lead_counts.iter().sum();
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+
+        // Castellated pads are extended outside like flat no-lead ones, LGA pads follow
+        // the terminals
+        let package_type = match comp_cfg
+            .get_str("package.terminal")
+            .unwrap_or("castellated")
+        {
+            "castellated" => PackageType::FlatNoLead,
+            "lga" => PackageType::PullBack,
+            _ => bail!(QedaError::InvalidElementType(
+                "package.terminal".to_string(),
+                "'castellated' or 'lga'"
+            )),
+        };
+        let ipc = |lead_span| -> Result<_> {
+            Ok(Ipc7351B::new(package_type)
+                .pitch(pitch)
+                .lead_span(lead_span)
+                .lead_len(comp_cfg.get_range("package.lead-length")?)
+                .lead_width(comp_cfg.get_range("package.lead-width")?)
+                .settings(lib_cfg)
+                .calc()
+                .post_proc(comp_cfg, lib_cfg))
+        };
+        let pad_props_x = ipc(body_size_x)?;
+        let pad_props_y = ipc(body_size_y)?;
+
+        let mut module = Module::default()
+            .pad_properties(pad_props_x, pad_props_y)
+            .pitch(pitch)
+            .lead_counts(lead_counts)
+            .body(body_size_x.nom(), body_size_y.nom())
+            .ground_pads(ground_pads(comp_cfg, lib_cfg, lead_count)?)
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+        if let Ok(depth) = comp_cfg.get_f64("package.antenna-size-y") {
+            module = module.antenna(depth);
+        }
+        if let Ok(offset) = comp_cfg.get_f64("package.row-offset") {
+            module = module.row_offset(offset);
+        }
+
+        let mut drawing = Drawing::new();
+        module.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw module model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
+
+// Get lead counts on the left, bottom, right and top sides
+fn lead_counts(comp_cfg: &Config) -> Result<[usize; 4]> {
+    let error = || {
+        QedaError::InvalidElementType(
+            "package.lead-counts".to_string(),
+            "array of 4 non-negative integers: left, bottom, right, top",
+        )
+    };
+    let counts = comp_cfg
+        .get_element("package.lead-counts")?
+        .as_array()
+        .filter(|a| a.len() == 4)
+        .ok_or_else(error)?;
+    let mut result = [0; 4];
+    for (i, count) in counts.iter().enumerate() {
+        let count = count
+            .as_f64()
+            .filter(|n| *n >= 0.0 && n.fract() == 0.0)
+            .ok_or_else(error)?;
+        result[i] = count as usize;
+    }
+    Ok(result)
+}
+
+// Create inner ground pads of `ground-pad-size` at `ground-pads` positions, all of them
+// share the same name
+fn ground_pads(comp_cfg: &Config, lib_cfg: &Config, lead_count: usize) -> Result<Vec<Pad>> {
+    let positions = match comp_cfg.get_element("package.ground-pads") {
+        Ok(positions) => positions,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let size = comp_cfg.get_pair("package.ground-pad-size")?;
+    let leads: Vec<String> = (1..=lead_count).map(|i| i.to_string()).collect();
+    let pad = pattern::thermal_pad(comp_cfg, lib_cfg, Size::new(size.0, size.1), &leads)?;
+
+    Ok(super::parse_pairs("package.ground-pads", positions)?
+        .into_iter()
+        .map(|(x, y)| pad.clone().origin(x, y))
+        .collect())
+}
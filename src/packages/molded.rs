@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::{Ipc7351B, TwoPin};
+
+use super::{PackageHandler, PackageType};
+
+pub struct MoldedPackage {}
+
+impl MoldedPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for MoldedPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw molded pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let lead_span = comp_cfg
+            .get_range("package.lead-span")
+            .unwrap_or(body_size_x);
+        let lead_style = comp_cfg.get_str("package.lead-style").unwrap_or("molded");
+
+        let pad_props = Ipc7351B::new(PackageType::from_str(lead_style)?)
+            .lead_span(lead_span)
+            .lead_len(comp_cfg.get_range("package.lead-length")?)
+            .lead_width(comp_cfg.get_range("package.lead-width")?)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let mut two_pin = TwoPin::default()
+            .pad_properties(pad_props)
+            .body(body_size_x.nom(), body_size_y.nom())
+            .polarized(comp_cfg.get_bool("package.polarized").unwrap_or(false));
+        if let Some(mark) = super::polarity_mark(comp_cfg)? {
+            two_pin = two_pin.polarity_mark(mark);
+        }
+
+        let mut drawing = Drawing::new();
+        two_pin.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw molded model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
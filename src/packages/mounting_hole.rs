@@ -0,0 +1,108 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Layer, Pad};
+use crate::error::*;
+use crate::pattern::{self, SinglePad};
+
+use super::PackageHandler;
+
+pub struct MountingHolePackage {}
+
+impl MountingHolePackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for MountingHolePackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw mounting hole pattern");
+
+        let hole = comp_cfg.get_f64("package.hole-diameter")?;
+        let mut single_pad = if comp_cfg.get_bool("package.plated").unwrap_or(false) {
+            let diameter = comp_cfg
+                .get_f64("package.pad-diameter")
+                .unwrap_or(2.0 * hole);
+            let layers =
+                Layer::COPPER_TOP | Layer::COPPER_BOTTOM | Layer::MASK_TOP | Layer::MASK_BOTTOM;
+            let pad = Pad::new("1")
+                .hole(hole, hole)
+                .size(diameter, diameter)
+                .layers(layers);
+            let mut single_pad = SinglePad::default().pad(pad).outline();
+            if let Ok(via_count) = comp_cfg.get_u64("package.via-count") {
+                let via_hole = comp_cfg.get_f64("package.via-diameter").unwrap_or(0.8);
+                let via = Pad::new("1")
+                    .via(via_hole, pattern::ring(via_hole, lib_cfg))
+                    .layers(layers);
+                single_pad = single_pad.vias(via_count as usize, via);
+            }
+            single_pad
+        } else {
+            SinglePad::default()
+                .pad(Pad::new("").non_plated(hole))
+                .outline()
+        };
+        if let Ok(keep_out) = comp_cfg.get_f64("package.keep-out-diameter") {
+            single_pad = single_pad.keep_out(keep_out);
+        }
+
+        let mut drawing = Drawing::new();
+        single_pad.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw mounting hole model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::{Element, PadKind};
+
+    // Get pads of a mounting hole described by the package YAML
+    fn pads(package: &str) -> Result<Vec<Pad>> {
+        let comp_cfg = Config::from_yaml(package)?;
+        let drawing =
+            MountingHolePackage::new().draw_pattern(&comp_cfg, &load_config!("../qeda.yml"))?;
+        Ok(drawing
+            .elements
+            .into_iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect())
+    }
+
+    #[test]
+    fn non_plated() -> Result<()> {
+        let pads = pads("package: { hole-diameter: 3.2 }")?;
+        assert_eq!(pads.len(), 1);
+        assert_eq!(pads[0].kind(), PadKind::NonPlated);
+        assert_eq!(pads[0].hole.as_ref().map(|h| h.x), Some(3.2));
+        Ok(())
+    }
+
+    #[test]
+    fn vias() -> Result<()> {
+        let pads = pads("package: { hole-diameter: 3.2, plated: true, via-count: 8 }")?;
+        assert_eq!(pads.len(), 9);
+        assert_eq!(pads[0].kind(), PadKind::ThroughHole);
+        assert_eq!((pads[0].size.x, pads[0].size.y), (6.4, 6.4));
+
+        // Vias in the middle of the annular ring are connected to the hole pad
+        for via in &pads[1..] {
+            assert_eq!(via.name, "1");
+            assert_eq!(via.kind(), PadKind::ThroughHole);
+            assert_eq!(via.hole.as_ref().map(|h| h.x), Some(0.8));
+            assert!((via.size.x - 1.2).abs() < 1e-9);
+            let r = via.origin.x.hypot(via.origin.y);
+            assert!((r - 2.4).abs() < 1e-9);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Size};
+use crate::error::*;
+use crate::pattern::{self, Ipc7351B, PowerTab};
+
+use super::{PackageHandler, PackageType};
+
+pub struct PakPackage {
+    lead_style: PackageType,
+    tab_style: PackageType,
+}
+
+impl PakPackage {
+    pub fn new(lead_style: PackageType, tab_style: PackageType) -> Self {
+        Self {
+            lead_style,
+            tab_style,
+        }
+    }
+}
+
+impl PackageHandler for PakPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw power package pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let leads = match comp_cfg.get_element("package.leads") {
+            Ok(leads) => super::parse_leads("package.leads", leads)?,
+            Err(_) => {
+                let lead_count = comp_cfg.get_u64("package.lead-count")?;
+                (1..=lead_count).map(|i| Some(i.to_string())).collect()
+            }
+        };
+        if let Ok(lead_count) = comp_cfg.get_u64("package.lead-count") {
+            ensure!(
+                leads.iter().flatten().count() as u64 == lead_count,
+                QedaError::InvalidLeadCount(lead_count, "it should match 'leads'")
+            );
+        }
+        let lead_style = match comp_cfg.get_str("package.lead-style") {
+            Ok(style) => style.parse()?,
+            Err(_) => self.lead_style,
+        };
+        let tab_style = match comp_cfg.get_str("package.tab-style") {
+            Ok(style) => style.parse()?,
+            Err(_) => self.tab_style,
+        };
+        let lead_span = comp_cfg.get_range("package.lead-span")?;
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_offset = comp_cfg.get_f64("package.body-offset").unwrap_or(0.0);
+
+        let lead_props = Ipc7351B::new(lead_style)
+            .pitch(pitch)
+            .lead_span(lead_span)
+            .lead_len(comp_cfg.get_range("package.lead-length")?)
+            .lead_width(comp_cfg.get_range("package.lead-width")?)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+        let mut tab_props = Ipc7351B::new(tab_style)
+            .lead_span(lead_span)
+            .lead_len(comp_cfg.get_range("package.tab-length")?)
+            .lead_width(comp_cfg.get_range("package.tab-width")?)
+            .settings(lib_cfg)
+            .calc();
+        if let Ok(tab_size) = comp_cfg.get_pair("pattern.tab-size") {
+            if !lib_cfg.get_bool("pattern.always-calculate").unwrap() {
+                tab_props.distance += tab_props.size.x - tab_size.0;
+                tab_props.size = Size::new(tab_size.0, tab_size.1);
+            }
+        }
+
+        let lead_names: Vec<String> = leads.iter().flatten().cloned().collect();
+        let tab = pattern::thermal_pad(comp_cfg, lib_cfg, tab_props.size.clone(), &lead_names)?;
+
+        let power_tab = PowerTab::default()
+            .pad_properties(lead_props, tab_props)
+            .pitch(pitch)
+            .leads(leads)
+            .tab(tab)
+            .body(body_size_x.nom(), body_size_y.nom(), body_offset)
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+
+        let mut drawing = Drawing::new();
+        power_tab.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw power package model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
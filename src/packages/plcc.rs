@@ -0,0 +1,67 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::{Ipc7351B, QuadRow};
+
+use super::{PackageHandler, PackageType};
+
+pub struct PlccPackage {}
+
+impl PlccPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for PlccPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw PLCC pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let (lead_count_x, lead_count_y) = super::quad_lead_counts(comp_cfg)?;
+        let lead_span_x = comp_cfg
+            .get_range("package.lead-span-x")
+            .or_else(|_| comp_cfg.get_range("package.lead-span"))?;
+        let lead_span_y = comp_cfg
+            .get_range("package.lead-span-y")
+            .or_else(|_| comp_cfg.get_range("package.lead-span"))?;
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg
+            .get_range("package.body-size-y")
+            .or_else(|_| comp_cfg.get_range("package.body-size-x"))?;
+
+        let ipc = |lead_span| -> Result<_> {
+            Ok(Ipc7351B::new(PackageType::JLead)
+                .pitch(pitch)
+                .lead_span(lead_span)
+                .lead_len(comp_cfg.get_range("package.lead-length")?)
+                .lead_width(comp_cfg.get_range("package.lead-width")?)
+                .settings(lib_cfg)
+                .calc()
+                .post_proc(comp_cfg, lib_cfg))
+        };
+        let pad_props_x = ipc(lead_span_x)?;
+        let pad_props_y = ipc(lead_span_y)?;
+
+        // Pin 1 is in the middle of the top row
+        let first_lead = 2 * lead_count_y + lead_count_x + lead_count_x / 2;
+
+        let quad_row = QuadRow::default()
+            .pad_properties(pad_props_x, pad_props_y)
+            .pitch(pitch)
+            .lead_counts(lead_count_x, lead_count_y)
+            .first_lead(first_lead)
+            .body(body_size_x.nom(), body_size_y.nom())
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+
+        let mut drawing = Drawing::new();
+        quad_row.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw PLCC model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
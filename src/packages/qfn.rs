@@ -0,0 +1,63 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::{self, Ipc7351B, QuadRow};
+
+use super::PackageHandler;
+
+pub struct QfnPackage {}
+
+impl QfnPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for QfnPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw QFN pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let (lead_count_x, lead_count_y) = super::quad_lead_counts(comp_cfg)?;
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg
+            .get_range("package.body-size-y")
+            .or_else(|_| comp_cfg.get_range("package.body-size-x"))?;
+        let (package_type, lead_span_x) = super::no_lead_span(comp_cfg, "x", body_size_x);
+        let (_, lead_span_y) = super::no_lead_span(comp_cfg, "y", body_size_y);
+
+        let ipc = |lead_span| -> Result<_> {
+            Ok(Ipc7351B::new(package_type)
+                .pitch(pitch)
+                .lead_span(lead_span)
+                .lead_len(comp_cfg.get_range("package.lead-length")?)
+                .lead_width(comp_cfg.get_range("package.lead-width")?)
+                .settings(lib_cfg)
+                .calc()
+                .post_proc(comp_cfg, lib_cfg))
+        };
+        let pad_props_x = ipc(lead_span_x)?;
+        let pad_props_y = ipc(lead_span_y)?;
+
+        let mut quad_row = QuadRow::default()
+            .pad_properties(pad_props_x, pad_props_y)
+            .pitch(pitch)
+            .lead_counts(lead_count_x, lead_count_y)
+            .body(body_size_x.nom(), body_size_y.nom())
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+        let lead_count = 2 * (lead_count_x + lead_count_y);
+        if let Some(pad) = pattern::exposed_pad(comp_cfg, lib_cfg, lead_count)? {
+            quad_row = quad_row.exposed_pad(pad);
+        }
+
+        let mut drawing = Drawing::new();
+        quad_row.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw QFN model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::Radial;
+
+use super::PackageHandler;
+
+pub struct RadialPackage {}
+
+impl RadialPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for RadialPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw radial pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let lead_diameter = super::lead_diameter(comp_cfg)?;
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+
+        let mut radial = Radial::default()
+            .pad(super::tht_pad(comp_cfg, lib_cfg, lead_diameter))
+            .pitch(pitch)
+            .body(body_diameter.nom());
+        if comp_cfg.get_bool("package.polarized").unwrap_or(false) {
+            radial = radial.polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+        }
+
+        let mut drawing = Drawing::new();
+        radial.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw radial model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
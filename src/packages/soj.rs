@@ -0,0 +1,55 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::{DualRow, Ipc7351B};
+
+use super::{PackageHandler, PackageType};
+
+pub struct SojPackage {}
+
+impl SojPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for SojPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw SOJ pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let lead_count = comp_cfg.get_u64("package.lead-count")?;
+        ensure!(
+            lead_count > 0 && lead_count % 2 == 0,
+            QedaError::InvalidLeadCount(lead_count, "it should be even")
+        );
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+
+        let pad_props = Ipc7351B::new(PackageType::JLead)
+            .pitch(pitch)
+            .lead_span(comp_cfg.get_range("package.lead-span")?)
+            .lead_len(comp_cfg.get_range("package.lead-length")?)
+            .lead_width(comp_cfg.get_range("package.lead-width")?)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let dual_row = DualRow::default()
+            .pad_properties(pad_props)
+            .pitch(pitch)
+            .lead_count(lead_count as usize)
+            .body(body_size_x.nom(), body_size_y.nom())
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+
+        let mut drawing = Drawing::new();
+        dual_row.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw SOJ model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
@@ -1,8 +1,9 @@
 use crate::config::Config;
 use crate::drawing::Drawing;
 use crate::error::*;
+use crate::pattern::{self, DualRow, Ipc7351B};
 
-use super::PackageHandler;
+use super::{PackageHandler, PackageType};
 
 pub struct SopPackage {}
 
@@ -13,9 +14,45 @@ impl SopPackage {
 }
 
 impl PackageHandler for SopPackage {
-    fn draw_pattern(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
         debug!("draw SOP pattern");
-        let drawing = Drawing::new();
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let lead_count = comp_cfg.get_u64("package.lead-count")?;
+        ensure!(
+            lead_count > 0 && lead_count % 2 == 0,
+            QedaError::InvalidLeadCount(lead_count, "it should be even")
+        );
+        let lead_span = comp_cfg.get_range("package.lead-span")?;
+        let lead_len = comp_cfg.get_range("package.lead-length")?;
+        let lead_width = comp_cfg.get_range("package.lead-width")?;
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_width = body_size_x.nom();
+        let body_height = body_size_y.nom();
+
+        let pad_props = Ipc7351B::new(PackageType::GullWing)
+            .pitch(pitch)
+            .body(body_width)
+            .lead_span(lead_span)
+            .lead_len(lead_len)
+            .lead_width(lead_width)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let mut dual_row = DualRow::default()
+            .pad_properties(pad_props)
+            .pitch(pitch)
+            .lead_count(lead_count as usize)
+            .body(body_width, body_height)
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+        if let Some(pad) = pattern::exposed_pad(comp_cfg, lib_cfg, lead_count as usize)? {
+            dual_row = dual_row.exposed_pad(pad);
+        }
+
+        let mut drawing = Drawing::new();
+        dual_row.draw(&mut drawing, lib_cfg);
         Ok(drawing)
     }
 
@@ -25,3 +62,48 @@ impl PackageHandler for SopPackage {
         Ok(drawing)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::Element;
+
+    #[test]
+    fn numbering() -> Result<()> {
+        let comp_cfg = Config::from_yaml(
+            r"
+            package:
+              pitch: 1.27
+              lead-count: 8
+              lead-span: 5.8..6.2
+              lead-length: 0.4..1.27
+              lead-width: 0.31..0.51
+              body-size-x: 3.8..4.0
+              body-size-y: 4.8..5.0
+            ",
+        )?;
+        let drawing = SopPackage::new().draw_pattern(&comp_cfg, &load_config!("../qeda.yml"))?;
+        let pads: Vec<_> = drawing
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(pads.len(), 8);
+
+        // Pin 1 is the top left one, pins go down the left side and up the right one
+        let pad = |name: &str| pads.iter().find(|p| p.name == name).unwrap().origin.clone();
+        let (p1, p4, p5, p8) = (pad("1"), pad("4"), pad("5"), pad("8"));
+        assert!(p1.x < 0.0 && p1.y < 0.0);
+        assert!(p4.x < 0.0 && p4.y > p1.y);
+        assert!(p5.x > 0.0 && p5.y == p4.y);
+        assert!(p8.x > 0.0 && p8.y == p1.y);
+        assert!(pads
+            .iter()
+            .all(|p| p.origin.y >= p1.y && p.origin.y <= p4.y));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::{DualRow, Ipc7351B};
+
+use super::{Leads, PackageHandler, PackageType};
+
+pub struct SotPackage {}
+
+impl SotPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for SotPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw SOT pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let lead_count = comp_cfg.get_u64("package.lead-count")?;
+        let (left, right) = match (
+            comp_cfg.get_element("package.leads-left"),
+            comp_cfg.get_element("package.leads-right"),
+        ) {
+            (Ok(left), Ok(right)) => (
+                super::parse_leads("package.leads-left", left)?,
+                super::parse_leads("package.leads-right", right)?,
+            ),
+            _ => default_leads(lead_count)?,
+        };
+        let actual_count = left.iter().chain(right.iter()).flatten().count() as u64;
+        ensure!(
+            actual_count == lead_count,
+            QedaError::InvalidLeadCount(
+                lead_count,
+                "it should match 'leads-left' and 'leads-right'"
+            )
+        );
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_width = body_size_x.nom();
+        let body_height = body_size_y.nom();
+
+        let pad_props = Ipc7351B::new(PackageType::GullWing)
+            .pitch(pitch)
+            .body(body_width)
+            .lead_span(comp_cfg.get_range("package.lead-span")?)
+            .lead_len(comp_cfg.get_range("package.lead-length")?)
+            .lead_width(comp_cfg.get_range("package.lead-width")?)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let dual_row = DualRow::default()
+            .pad_properties(pad_props)
+            .pitch(pitch)
+            .lead_layout(left, right)
+            .body(body_width, body_height)
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+
+        let mut drawing = Drawing::new();
+        dual_row.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw SOT model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
+
+// Get lead layouts of the most common SOT packages
+fn default_leads(lead_count: u64) -> Result<(Leads, Leads)> {
+    let leads = |numbers: &[u64]| -> Leads {
+        numbers
+            .iter()
+            .map(|&n| if n > 0 { Some(n.to_string()) } else { None })
+            .collect()
+    };
+    Ok(match lead_count {
+        3 => (leads(&[1, 0, 2]), leads(&[0, 3, 0])), // SOT-23-3, SOT-323, SC-70-3
+        5 => (leads(&[1, 2, 3]), leads(&[5, 0, 4])), // SOT-23-5, SC-70-5
+        n if n > 0 && n % 2 == 0 => {
+            let row_count = n / 2;
+            let left: Vec<_> = (1..=row_count).collect();
+            let right: Vec<_> = (row_count + 1..=n).rev().collect();
+            (leads(&left), leads(&right))
+        }
+        n => bail!(QedaError::InvalidLeadCount(
+            n,
+            "'leads-left' and 'leads-right' should be specified"
+        )),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::Element;
+
+    // Get (column, row) positions of SOT pads with the specified lead layout
+    fn positions(layout: &[&str]) -> Result<Vec<(String, (i32, i32))>> {
+        let comp_cfg = Config::from_yaml(&format!(
+            r"
+            package:
+              pitch: 0.95
+              lead-span: 2.1..2.64
+              lead-length: 0.3..0.6
+              lead-width: 0.3..0.5
+              body-size-x: 1.2..1.4
+              body-size-y: 2.8..3.04
+              {}
+            ",
+            layout.join("\n              ")
+        ))?;
+        let drawing = SotPackage::new().draw_pattern(&comp_cfg, &load_config!("../qeda.yml"))?;
+        Ok(drawing
+            .elements
+            .into_iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some((
+                    pad.name,
+                    (
+                        pad.origin.x.signum() as i32,
+                        (pad.origin.y / 0.95).round() as i32,
+                    ),
+                )),
+                _ => None,
+            })
+            .collect())
+    }
+
+    #[test]
+    fn layouts() -> Result<()> {
+        // 2+1: pins 1 and 2 at the left corners, pin 3 in the middle of the right side
+        let expected: Vec<(String, (i32, i32))> = vec![
+            ("1".to_string(), (-1, -1)),
+            ("2".to_string(), (-1, 1)),
+            ("3".to_string(), (1, 0)),
+        ];
+        assert_eq!(positions(&["lead-count: 3"])?, expected);
+        let layout = [
+            "lead-count: 3",
+            "leads-left: [1, 0, 2]",
+            "leads-right: ['-', 3, ~]",
+        ];
+        assert_eq!(positions(&layout)?, expected);
+
+        // 3+2: the right side has a gap in the middle
+        let positions = positions(&["lead-count: 5"])?;
+        assert_eq!(positions.len(), 5);
+        assert!(positions.contains(&("3".to_string(), (-1, 1))));
+        assert!(positions.contains(&("4".to_string(), (1, 1))));
+        assert!(positions.contains(&("5".to_string(), (1, -1))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_leads() {
+        for lead in &["2.5", "-1"] {
+            let right = format!("leads-right: [0, {}, 0]", lead);
+            let layout = ["lead-count: 3", "leads-left: [1, 0, 2]", &right];
+            assert!(positions(&layout).is_err());
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Layer, Pad, PadShape};
+use crate::error::*;
+use crate::pattern::SinglePad;
+
+use super::PackageHandler;
+
+pub struct TestPointPackage {}
+
+impl TestPointPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for TestPointPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw test point pattern");
+
+        let pad = if comp_cfg.get_str("package.mount").unwrap_or("smd") == "tht" {
+            let lead_diameter = super::lead_diameter(comp_cfg)?;
+            super::tht_pad(comp_cfg, lib_cfg, lead_diameter).name("1")
+        } else {
+            let diameter = comp_cfg.get_f64("package.pad-diameter")?;
+            Pad::new("1")
+                .shape(PadShape::Circle)
+                .size(diameter, diameter)
+                .layers(Layer::COPPER_TOP | Layer::MASK_TOP)
+        };
+        let single_pad = SinglePad::default().pad(pad).outline();
+
+        let mut drawing = Drawing::new();
+        single_pad.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw test point model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::Element;
+
+    #[test]
+    fn smd() -> Result<()> {
+        let comp_cfg = Config::from_yaml("package: { pad-diameter: 1.5 }")?;
+        let drawing =
+            TestPointPackage::new().draw_pattern(&comp_cfg, &load_config!("../qeda.yml"))?;
+        let pads: Vec<_> = drawing
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect();
+
+        // Round pad without paste
+        assert_eq!(pads.len(), 1);
+        assert_eq!(pads[0].name, "1");
+        assert!(pads[0].is_smd() && matches!(pads[0].shape, PadShape::Circle));
+        assert_eq!((pads[0].size.x, pads[0].size.y), (1.5, 1.5));
+        assert_eq!(pads[0].layers, Layer::COPPER_TOP | Layer::MASK_TOP);
+        Ok(())
+    }
+}
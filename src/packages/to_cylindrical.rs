@@ -0,0 +1,65 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::Cylindrical;
+
+use super::PackageHandler;
+
+pub struct ToCylindricalPackage {}
+
+impl ToCylindricalPackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for ToCylindricalPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw TO cylindrical pattern");
+
+        let lead_count = comp_cfg.get_u64("package.lead-count")?;
+        ensure!(
+            lead_count > 0,
+            QedaError::InvalidLeadCount(lead_count, "it should be positive")
+        );
+        let lead_diameter = super::lead_diameter(comp_cfg)?;
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+        let flat = comp_cfg.get_f64("package.flat").ok();
+
+        let mut cylindrical = Cylindrical::default()
+            .pad(super::tht_pad(comp_cfg, lib_cfg, lead_diameter))
+            .lead_count(lead_count as usize)
+            .body(body_diameter.nom(), flat)
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+        match comp_cfg
+            .get_str("package.lead-arrangement")
+            .unwrap_or("inline")
+        {
+            "inline" => {
+                cylindrical = cylindrical
+                    .pitch(comp_cfg.get_f64("package.pitch")?)
+                    .stagger(comp_cfg.get_f64("package.stagger").unwrap_or(0.0));
+            }
+            "circle" => {
+                cylindrical = cylindrical.lead_circle(
+                    comp_cfg.get_f64("package.lead-circle")?,
+                    comp_cfg.get_f64("package.lead-angle").unwrap_or(90.0),
+                );
+            }
+            _ => bail!(QedaError::InvalidElementType(
+                "package.lead-arrangement".to_string(),
+                "'inline' or 'circle'"
+            )),
+        }
+
+        let mut drawing = Drawing::new();
+        cylindrical.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw TO cylindrical model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pattern::Flange;
+
+use super::PackageHandler;
+
+pub struct ToFlangePackage {}
+
+impl ToFlangePackage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PackageHandler for ToFlangePackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw TO flange pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let lead_count = comp_cfg.get_u64("package.lead-count")?;
+        ensure!(
+            lead_count > 0,
+            QedaError::InvalidLeadCount(lead_count, "it should be positive")
+        );
+        let lead_diameter = super::lead_diameter(comp_cfg)?;
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_offset = comp_cfg.get_f64("package.body-offset").unwrap_or(0.0);
+
+        let mut flange = Flange::default()
+            .pad(super::tht_pad(comp_cfg, lib_cfg, lead_diameter))
+            .pitch(pitch)
+            .lead_count(lead_count as usize)
+            .stagger(comp_cfg.get_f64("package.stagger").unwrap_or(0.0))
+            .body(body_size_x.nom(), body_size_y.nom(), body_offset)
+            .polarity_mark(super::pin1_mark(comp_cfg, lib_cfg)?);
+        if let Ok(tab_size_y) = comp_cfg.get_range("package.tab-size-y") {
+            flange = flange.tab(tab_size_y.nom());
+        }
+
+        let mut drawing = Drawing::new();
+        flange.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw TO flange model");
+        let drawing = Drawing::new();
+        Ok(drawing)
+    }
+}
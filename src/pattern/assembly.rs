@@ -0,0 +1,190 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+// Maximum size of the pin 1 corner chamfer
+const MAX_CHAMFER: f64 = 1.0;
+
+/// Draws the nominal body outline on assembly with the reference designator inside.
+///
+/// If `pin1` is specified, the body corner nearest to it is chamfered.
+pub fn draw_body(drawing: &mut Drawing, body: &Rect, pin1: Option<&Pad>, lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+    let (x0, y0, x1, y1) = (body.p.0.x, body.p.0.y, body.p.1.x, body.p.1.y);
+    let corners = [(x0, y0), (x1, y0), (x1, y1), (x0, y1)];
+    let mut points = Vec::with_capacity(6);
+    match pin1 {
+        Some(pad) => {
+            let distance = |&(x, y): &(f64, f64)| (x - pad.origin.x).hypot(y - pad.origin.y);
+            let k = (0..4)
+                .min_by(|&a, &b| {
+                    distance(&corners[a])
+                        .partial_cmp(&distance(&corners[b]))
+                        .unwrap()
+                })
+                .unwrap_or_default();
+            let chamfer = ((x1 - x0).min(y1 - y0) / 4.0).min(MAX_CHAMFER);
+            for i in 0..4 {
+                let (x, y) = corners[(k + i) % 4];
+                if i == 0 {
+                    // Cut the corner: points towards the previous and the next corners
+                    let (px, py) = corners[(k + 3) % 4];
+                    let (nx, ny) = corners[(k + 1) % 4];
+                    let towards = |tx: f64, ty: f64| {
+                        let d = (tx - x).hypot(ty - y);
+                        (x + (tx - x) * chamfer / d, y + (ty - y) * chamfer / d)
+                    };
+                    points.push(towards(px, py));
+                    points.push(towards(nx, ny));
+                } else {
+                    points.push((x, y));
+                }
+            }
+        }
+        None => points.extend_from_slice(&corners),
+    }
+    points.push(points[0]);
+    for p in points.windows(2) {
+        drawing.add_line(
+            Line::new(p[0].0, p[0].1, p[1].0, p[1].1)
+                .width(line_width)
+                .layer(Layer::ASSEMBLY_TOP),
+        );
+    }
+
+    draw_ref_des(drawing, body, lib_cfg);
+}
+
+/// Draws a pin 1 dot on assembly.
+pub fn draw_dot(drawing: &mut Drawing, center: Point, lib_cfg: &Config) {
+    let size = lib_cfg.get_f64("pattern.polarity.size").unwrap();
+    drawing.add_circle(
+        Circle::new(center.x, center.y, size / 4.0)
+            .width(size / 2.0)
+            .layer(Layer::ASSEMBLY_TOP),
+    );
+}
+
+/// Draws the reference designator on assembly in the body center.
+///
+/// Font size is `pattern.font-size.ref-des` but reduced to fit three characters inside
+/// the body.
+pub fn draw_ref_des(drawing: &mut Drawing, body: &Rect, lib_cfg: &Config) {
+    let font_size = lib_cfg.get_f64("pattern.font-size.ref-des").unwrap();
+    let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+    let (width, height) = (body.p.1.x - body.p.0.x, body.p.1.y - body.p.0.y);
+    let font_size = font_size.min(width / 3.0).min(height / 2.0);
+    let font_size = (font_size / 0.01 + 1e-6).floor() * 0.01;
+    if font_size <= 0.0 {
+        return;
+    }
+    drawing.add_attribute(
+        Attribute::new("ref-des-assembly", "%R")
+            .origin(
+                (body.p.0.x + body.p.1.x) / 2.0,
+                (body.p.0.y + body.p.1.y) / 2.0,
+            )
+            .font_size(font_size)
+            .line_width(line_width.min(font_size / 5.0))
+            .align(HAlign::Center, VAlign::Middle)
+            .layer(Layer::ASSEMBLY_TOP),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outline(drawing: &Drawing) -> Vec<(f64, f64)> {
+        let lines: Vec<_> = drawing
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Line(line) => Some(line),
+                _ => None,
+            })
+            .collect();
+        // Lines are chained, so the start points make the outline
+        let round = |v: f64| (v * 1e6).round() / 1e6;
+        lines
+            .iter()
+            .map(|l| (round(l.p.0.x), round(l.p.0.y)))
+            .collect()
+    }
+
+    #[test]
+    fn chamfer() {
+        let lib_cfg = load_config!("../qeda.yml");
+        let body = Rect::new(-2.0, -1.0, 2.0, 1.0);
+
+        let mut drawing = Drawing::new();
+        draw_body(&mut drawing, &body, None, &lib_cfg);
+        assert_eq!(
+            outline(&drawing),
+            [(-2.0, -1.0), (2.0, -1.0), (2.0, 1.0), (-2.0, 1.0)]
+        );
+
+        // Chamfer is a quarter of the smaller body side
+        let mut drawing = Drawing::new();
+        let pin1 = Pad::new("1").origin(-1.5, -0.8);
+        draw_body(&mut drawing, &body, Some(&pin1), &lib_cfg);
+        assert_eq!(
+            outline(&drawing),
+            [
+                (-2.0, -0.5),
+                (-1.5, -1.0),
+                (2.0, -1.0),
+                (2.0, 1.0),
+                (-2.0, 1.0)
+            ]
+        );
+
+        let mut drawing = Drawing::new();
+        let pin1 = Pad::new("1").origin(1.5, 0.8);
+        draw_body(&mut drawing, &body, Some(&pin1), &lib_cfg);
+        assert_eq!(
+            outline(&drawing),
+            [
+                (2.0, 0.5),
+                (1.5, 1.0),
+                (-2.0, 1.0),
+                (-2.0, -1.0),
+                (2.0, -1.0)
+            ]
+        );
+
+        // Chamfer is limited for large bodies
+        let mut drawing = Drawing::new();
+        let body = Rect::new(-10.0, -10.0, 10.0, 10.0);
+        let pin1 = Pad::new("1").origin(-9.0, -9.0);
+        draw_body(&mut drawing, &body, Some(&pin1), &lib_cfg);
+        assert_eq!(outline(&drawing)[..2], [(-10.0, -9.0), (-9.0, -10.0)]);
+    }
+
+    #[test]
+    fn ref_des() {
+        let lib_cfg = load_config!("../qeda.yml");
+        let attribute = |body: &Rect| {
+            let mut drawing = Drawing::new();
+            draw_ref_des(&mut drawing, body, &lib_cfg);
+            drawing.elements.into_iter().find_map(|e| match e {
+                Element::Attribute(a) => Some(a),
+                _ => None,
+            })
+        };
+
+        let a = attribute(&Rect::new(-2.0, -1.0, 2.0, 1.0)).unwrap();
+        assert_eq!(a.id, "ref-des-assembly");
+        assert_eq!((a.origin.x, a.origin.y), (0.0, 0.0));
+        assert_eq!((a.font_size, a.line_width), (1.0, 0.1));
+        assert!(matches!(a.halign, HAlign::Center));
+        assert!(matches!(a.valign, VAlign::Middle));
+
+        // Font is reduced to fit three characters in a small body
+        let a = attribute(&Rect::new(0.7, 0.35, 1.3, 0.65)).unwrap();
+        assert_eq!((a.origin.x, a.origin.y), (1.0, 0.5));
+        assert_eq!(a.font_size, 0.15);
+        assert!((a.line_width - 0.03).abs() < 1e-9);
+
+        assert!(attribute(&Rect::new(0.0, 0.0, 0.0, 0.0)).is_none());
+    }
+}
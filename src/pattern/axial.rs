@@ -0,0 +1,249 @@
+use std::f64::consts::PI;
+
+use crate::config::Config;
+use crate::drawing::*;
+use crate::packages::PackageType;
+
+use super::{assembly, courtyard, mask, silkscreen};
+
+/// Pattern of a through-hole part with axial leads.
+#[derive(Debug, Default)]
+pub struct Axial {
+    pad: Pad,
+    pitch: f64,
+    body: Rect,
+    rounded: bool,
+    polarized: bool,
+    polarity_mark: Option<String>,
+}
+
+impl Axial {
+    /// Builds an `Axial` with modified body.
+    pub fn body(mut self, length: f64, diameter: f64) -> Self {
+        self.body.p.0.x = -length / 2.0;
+        self.body.p.0.y = -diameter / 2.0;
+        self.body.p.1.x = length / 2.0;
+        self.body.p.1.y = diameter / 2.0;
+        self
+    }
+
+    /// Draws axial pattern.
+    ///
+    /// A polarized part has a cathode band on the pin 1 side of the body.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let x = self.pitch / 2.0;
+        let mut pads = vec![
+            self.pad
+                .clone()
+                .name("1")
+                .shape(PadShape::Rect)
+                .origin(-x, 0.0),
+            self.pad.clone().name("2").origin(x, 0.0),
+        ];
+        mask::calc(&mut pads, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let body = self.body.clone().expand(line_width / 2.0);
+        let mut lines = if self.rounded {
+            rounded_outline(&body)
+        } else {
+            body.to_lines()
+        };
+        // Leads between the body and the pads
+        if body.p.1.x < x {
+            lines.push(Line::new(-x, 0.0, body.p.0.x, 0.0));
+            lines.push(Line::new(body.p.1.x, 0.0, x, 0.0));
+        }
+        let band = self.polarized && matches!(self.polarity_mark.as_deref(), None | Some("bar"));
+        if band {
+            let x = self.band_x();
+            lines.push(Line::new(x, body.p.0.y, x, body.p.1.y));
+        }
+        let lines = lines
+            .into_iter()
+            .map(|l| l.width(line_width).layer(Layer::SILKSCREEN_TOP))
+            .collect();
+        silkscreen::draw_clipped(drawing, lines, &pads, lib_cfg);
+        if let (true, false, Some(mark)) = (self.polarized, band, &self.polarity_mark) {
+            silkscreen::draw_pin1_mark(drawing, mark, &pads[0], &pads, lib_cfg);
+        }
+
+        if self.rounded {
+            let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+            drawing.add_lines(
+                rounded_outline(&self.body)
+                    .into_iter()
+                    .map(|l| l.width(line_width).layer(Layer::ASSEMBLY_TOP))
+                    .collect(),
+            );
+            assembly::draw_ref_des(drawing, &self.body, lib_cfg);
+        } else {
+            assembly::draw_body(drawing, &self.body, None, lib_cfg);
+        }
+        if self.polarized {
+            let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+            let x = self.band_x();
+            drawing.add_line(
+                Line::new(x, self.body.p.0.y, x, self.body.p.1.y)
+                    .width(line_width)
+                    .layer(Layer::ASSEMBLY_TOP),
+            );
+        }
+
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&self.body),
+            courtyard::excess(PackageType::ThroughHole, lib_cfg),
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds an `Axial` with modified body shape: semicircular ends instead of flat ones.
+    pub fn rounded(mut self, rounded: bool) -> Self {
+        self.rounded = rounded;
+        self
+    }
+
+    /// Builds an `Axial` with modified template pad.
+    pub fn pad(mut self, pad: Pad) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    /// Builds an `Axial` with modified distance between leads.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds an `Axial` with modified polarity.
+    pub fn polarized(mut self, polarized: bool) -> Self {
+        self.polarized = polarized;
+        self
+    }
+
+    /// Builds an `Axial` with a polarity mark near pin 1 of a polarized part.
+    ///
+    /// Mark `bar` (default) is a cathode band across the body.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+
+    // Get X coordinate of the cathode band
+    fn band_x(&self) -> f64 {
+        self.body.p.0.x + (self.body.p.1.x - self.body.p.0.x) / 5.0
+    }
+}
+
+// Get lines of a rectangle outline with semicircular left and right sides
+fn rounded_outline(rect: &Rect) -> Vec<Line> {
+    let (x0, y0, x1, y1) = (rect.p.0.x, rect.p.0.y, rect.p.1.x, rect.p.1.y);
+    let r = (y1 - y0) / 2.0;
+    let yc = (y0 + y1) / 2.0;
+    let mut lines = vec![
+        Line::new(x0 + r, y0, x1 - r, y0),
+        Line::new(x0 + r, y1, x1 - r, y1),
+    ];
+    let count = 16;
+    for (xc, a0) in &[(x1 - r, -PI / 2.0), (x0 + r, PI / 2.0)] {
+        let point = |i: usize| {
+            let a = a0 + PI * i as f64 / count as f64;
+            (xc + r * a.cos(), yc + r * a.sin())
+        };
+        for i in 0..count {
+            let (xa, ya) = point(i);
+            let (xb, yb) = point(i + 1);
+            lines.push(Line::new(xa, ya, xb, yb));
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads() {
+        let pad = Pad::new("")
+            .hole(0.8, 0.8)
+            .size(1.6, 1.6)
+            .layers(Layer::COPPER_TOP | Layer::COPPER_BOTTOM);
+        let mut drawing = Drawing::new();
+        Axial::default()
+            .pad(pad)
+            .pitch(10.16)
+            .body(6.3, 2.5)
+            .draw(&mut drawing, &load_config!("../qeda.yml"));
+        let pads: Vec<_> = drawing
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect();
+
+        // Square pin 1 on the left
+        assert_eq!(pads.len(), 2);
+        assert_eq!(pads[0].name, "1");
+        assert_eq!((pads[0].origin.x, pads[0].origin.y), (-5.08, 0.0));
+        assert!(matches!(pads[0].shape, PadShape::Rect));
+        assert_eq!(pads[1].name, "2");
+        assert_eq!((pads[1].origin.x, pads[1].origin.y), (5.08, 0.0));
+        assert!(matches!(pads[1].shape, PadShape::Circle));
+    }
+
+    #[test]
+    fn polarity() {
+        let pad = Pad::new("")
+            .hole(1.0, 1.0)
+            .size(1.5, 1.5)
+            .layers(Layer::COPPER_TOP | Layer::COPPER_BOTTOM);
+        let axial = || {
+            Axial::default()
+                .pad(pad.clone())
+                .pitch(7.8)
+                .body(4.65, 2.35)
+                .polarized(true)
+        };
+        // Get vertical lines inside the body and silkscreen circles
+        let marks = |axial: Axial| {
+            let mut drawing = Drawing::new();
+            axial.draw(&mut drawing, &load_config!("../qeda.yml"));
+            let mut bands = Vec::new();
+            let mut dots = 0;
+            for e in drawing.elements {
+                match e {
+                    Element::Line(l) if l.p.0.x == l.p.1.x && l.p.0.x.abs() < 2.0 => {
+                        bands.push((l.layer, (l.p.0.x * 1e6).round() / 1e6))
+                    }
+                    Element::Circle(c) if c.layer == Layer::SILKSCREEN_TOP => dots += 1,
+                    _ => (),
+                }
+            }
+            (bands, dots)
+        };
+
+        // Cathode band on the pin 1 side
+        let (bands, dots) = marks(axial());
+        assert_eq!(
+            bands,
+            [
+                (Layer::SILKSCREEN_TOP, -1.395),
+                (Layer::ASSEMBLY_TOP, -1.395)
+            ]
+        );
+        assert_eq!(dots, 0);
+
+        let (bands, dots) = marks(axial().polarity_mark("."));
+        assert_eq!(bands, [(Layer::ASSEMBLY_TOP, -1.395)]);
+        assert_eq!(dots, 1);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+use super::{assembly, courtyard, mask, silkscreen, PadProperties};
+
+/// Pattern of an aluminium electrolytic capacitor with a chamfered square base.
+#[derive(Debug, Default)]
+pub struct Cae {
+    pad_props: PadProperties,
+    body: Rect,
+    chamfer: f64,
+    polarity_mark: Option<String>,
+}
+
+impl Cae {
+    /// Builds a `Cae` with modified base size and chamfer of the positive side corners.
+    pub fn body(mut self, width: f64, height: f64, chamfer: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self.chamfer = chamfer;
+        self
+    }
+
+    /// Draws CAE pattern.
+    ///
+    /// Pin 1 (positive) is on the left. The negative side is marked with `-` by default,
+    /// other marks are placed near pin 1.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let pad_left = Pad::new("1")
+            .shape(PadShape::Rect)
+            .size(self.pad_props.size.x, self.pad_props.size.y)
+            .origin(-self.pad_props.distance / 2.0, 0.0)
+            .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP);
+        let pad_right = pad_left
+            .clone()
+            .name("2")
+            .origin(self.pad_props.distance / 2.0, 0.0);
+
+        let mut pads = vec![pad_left, pad_right];
+        mask::calc(&mut pads, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let lines = self.outline(line_width / 2.0, line_width, Layer::SILKSCREEN_TOP);
+        silkscreen::draw_clipped(drawing, lines, &pads, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+        drawing.add_lines(self.outline(0.0, line_width, Layer::ASSEMBLY_TOP));
+
+        match self.polarity_mark.as_deref().unwrap_or("-") {
+            "-" => silkscreen::draw_pin1_mark(drawing, "-", &pads[1], &pads, lib_cfg),
+            mark => silkscreen::draw_pin1_mark(drawing, mark, &pads[0], &pads, lib_cfg),
+        }
+
+        assembly::draw_ref_des(drawing, &self.body, lib_cfg);
+
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&self.body),
+            self.pad_props.courtyard,
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `Cae` with a polarity mark.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+
+    /// Builds a `Cae` with modified pad properties.
+    pub fn pad_properties(mut self, pad_props: PadProperties) -> Self {
+        self.pad_props = pad_props;
+        self
+    }
+
+    // Get outline lines of the base expanded by `d`
+    fn outline(&self, d: f64, line_width: f64, layer: Layer) -> Vec<Line> {
+        let rect = self.body.clone().expand(d);
+        let (x0, y0, x1, y1) = (rect.p.0.x, rect.p.0.y, rect.p.1.x, rect.p.1.y);
+        let c = self.chamfer;
+        let points = [
+            (x0 + c, y0),
+            (x1, y0),
+            (x1, y1),
+            (x0 + c, y1),
+            (x0, y1 - c),
+            (x0, y0 + c),
+            (x0 + c, y0),
+        ];
+        points
+            .windows(2)
+            .map(|p| {
+                Line::new(p[0].0, p[0].1, p[1].0, p[1].1)
+                    .width(line_width)
+                    .layer(layer)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Get pads of a drawn pattern
+    fn pads(cae: &Cae) -> Vec<Pad> {
+        let mut drawing = Drawing::new();
+        cae.draw(&mut drawing, &load_config!("../qeda.yml"));
+        drawing
+            .elements
+            .into_iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn layout() {
+        let pad_props = PadProperties {
+            size: Size::new(2.12, 1.42),
+            distance: 6.5,
+            ..Default::default()
+        };
+        let cae = Cae::default().pad_properties(pad_props).body(6.6, 6.6, 1.6);
+        let pads = pads(&cae);
+
+        // Positive pin 1 is on the left
+        assert_eq!(pads.len(), 2);
+        assert_eq!(pads[0].name, "1");
+        assert_eq!((pads[0].origin.x, pads[0].origin.y), (-3.25, 0.0));
+        assert_eq!(pads[1].name, "2");
+        assert_eq!((pads[1].origin.x, pads[1].origin.y), (3.25, 0.0));
+    }
+
+    // Get X coordinates of silkscreen lines outside the pads
+    fn marks(cae: &Cae) -> Vec<f64> {
+        let mut drawing = Drawing::new();
+        cae.draw(&mut drawing, &load_config!("../qeda.yml"));
+        drawing
+            .elements
+            .into_iter()
+            .filter_map(|e| match e {
+                Element::Line(l) if l.layer == Layer::SILKSCREEN_TOP => Some(l),
+                _ => None,
+            })
+            .map(|l| (l.p.0.x + l.p.1.x) / 2.0)
+            .filter(|x| x.abs() > 4.31)
+            .collect()
+    }
+
+    #[test]
+    fn polarity() {
+        let pad_props = PadProperties {
+            size: Size::new(2.12, 1.42),
+            distance: 6.5,
+            ..Default::default()
+        };
+        let cae = || {
+            Cae::default()
+                .pad_properties(pad_props.clone())
+                .body(6.6, 6.6, 1.6)
+        };
+
+        // Negative side is marked by default
+        let marks = self::marks(&cae());
+        assert_eq!(marks.len(), 1);
+        assert!(marks[0] > 0.0);
+
+        let marks = self::marks(&cae().polarity_mark("+"));
+        assert_eq!(marks.len(), 2);
+        assert!(marks.iter().all(|&x| x < 0.0));
+    }
+}
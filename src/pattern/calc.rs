@@ -33,6 +33,31 @@ impl Ipc7351B {
         }
     }
 
+    /// Calculates a land diameter for a collapsing ball of the specified nominal diameter.
+    ///
+    /// Land is reduced relative to the ball according to IPC-7351B BGA tables.
+    pub fn ball_land(ball_diameter: f64) -> f64 {
+        let reduction = if ball_diameter >= 0.55 {
+            0.25
+        } else if ball_diameter >= 0.25 {
+            0.2
+        } else {
+            0.15
+        };
+        Self::round_size(ball_diameter * (1.0 - reduction))
+    }
+
+    /// Sets the body width, i.e. a size of the body between the lead rows.
+    pub fn body(mut self, body: f64) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Returns the courtyard excess for the density level.
+    pub fn courtyard(&self) -> f64 {
+        self.courtyard
+    }
+
     /// Sets a lead span, i.e. a distance from the one lead edge to the opposite lead edge.
     pub fn lead_span(mut self, span: Range) -> Self {
         self.lead_span = span;
@@ -57,6 +82,14 @@ impl Ipc7351B {
         self
     }
 
+    /// Sets a distance between adjacent leads.
+    ///
+    /// Should be called before `settings` because goals depend on the pitch.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = Some(pitch);
+        self
+    }
+
     /// Calculates pad parameters.
     pub fn calc(self) -> PadProperties {
         let span_tol = self.lead_span.tol();
@@ -210,6 +243,83 @@ impl Ipc7351B {
                     )
                 }
             }
+            PackageType::Bga => (
+                // Lands are calculated from balls, only the courtyard is used
+                0.0,
+                0.0,
+                0.0,
+                vec![2.0, 1.0, 0.5][i], // Coutyard
+            ),
+            PackageType::Cae => (
+                vec![0.7, 0.5, 0.3][i],   // Toe
+                vec![0.0, -0.1, -0.2][i], // Heel
+                vec![0.5, 0.3, 0.1][i],   // Side
+                vec![1.0, 0.5, 0.25][i],  // Coutyard
+            ),
+            PackageType::ConcaveArray => (
+                vec![0.55, 0.45, 0.35][i],   // Toe
+                vec![-0.05, -0.07, -0.1][i], // Heel
+                vec![0.05, 0.0, -0.05][i],   // Side
+                vec![0.5, 0.2, 0.1][i],      // Coutyard
+            ),
+            PackageType::ConvexArray => (
+                vec![0.55, 0.45, 0.35][i],   // Toe
+                vec![-0.05, -0.07, -0.1][i], // Heel
+                vec![-0.05, -0.07, -0.1][i], // Side
+                vec![0.5, 0.2, 0.1][i],      // Coutyard
+            ),
+            PackageType::FlatLug => (
+                vec![0.55, 0.45, 0.35][i], // Toe
+                vec![0.45, 0.35, 0.25][i], // Heel
+                vec![0.05, 0.0, -0.05][i], // Side
+                vec![0.5, 0.25, 0.12][i],  // Coutyard
+            ),
+            PackageType::FlatNoLead => (
+                vec![0.4, 0.3, 0.2][i],       // Toe
+                vec![0.0, 0.0, 0.0][i],       // Heel
+                vec![-0.04, -0.04, -0.04][i], // Side
+                vec![0.5, 0.25, 0.12][i],     // Coutyard
+            ),
+            PackageType::JLead => (
+                // Leads are bent under the body, so the body width is not set to trim pads
+                vec![0.55, 0.35, 0.15][i], // Toe
+                vec![0.1, 0.0, -0.1][i],   // Heel
+                vec![0.05, 0.03, 0.01][i], // Side
+                vec![0.5, 0.25, 0.12][i],  // Coutyard
+            ),
+            PackageType::Melf => (
+                // Cylindrical end caps
+                vec![0.6, 0.4, 0.2][i],   // Toe
+                vec![0.2, 0.1, 0.02][i],  // Heel
+                vec![0.1, 0.05, 0.01][i], // Side
+                vec![0.4, 0.2, 0.1][i],   // Coutyard
+            ),
+            PackageType::Molded => (
+                // Inward L-bend leads
+                vec![0.25, 0.15, 0.07][i],  // Toe
+                vec![0.8, 0.5, 0.2][i],     // Heel
+                vec![0.01, -0.05, -0.1][i], // Side
+                vec![0.5, 0.25, 0.1][i],    // Coutyard
+            ),
+            PackageType::PullBack => (
+                vec![0.05, 0.0, -0.05][i],    // Toe
+                vec![0.0, 0.0, 0.0][i],       // Heel
+                vec![-0.04, -0.04, -0.04][i], // Side
+                vec![0.5, 0.25, 0.12][i],     // Coutyard
+            ),
+            PackageType::ThroughHole => (
+                // IPC-7251, lands are calculated from holes, only the courtyard is used
+                0.0,
+                0.0,
+                0.0,
+                vec![0.5, 0.25, 0.12][i], // Coutyard
+            ),
+            PackageType::GullWing if self.pitch.unwrap_or(1.0) <= 0.625 => (
+                vec![0.55, 0.35, 0.15][i],   // Toe
+                vec![0.45, 0.35, 0.25][i],   // Heel
+                vec![0.01, -0.02, -0.04][i], // Side
+                vec![0.5, 0.25, 0.12][i],    // Coutyard
+            ),
             _ => (
                 vec![0.55, 0.35, 0.15][i], // Toe
                 vec![0.45, 0.35, 0.25][i], // Heel
@@ -241,6 +351,17 @@ impl Ipc7351B {
 mod tests {
     use super::*;
 
+    // Get pad distance, length, width and courtyard excess without floating point noise
+    fn values(pad_props: &PadProperties) -> [f64; 4] {
+        [
+            pad_props.distance,
+            pad_props.size.x,
+            pad_props.size.y,
+            pad_props.courtyard,
+        ]
+        .map(|v| (v * 1e6).round() / 1e6)
+    }
+
     #[test]
     fn ipc() {
         // Use calculator from pcblibraries.com for validation
@@ -256,4 +377,107 @@ mod tests {
         assert_eq!(pad_props.size.y, 0.6);
         assert_eq!(pad_props.courtyard, 0.25);
     }
+
+    #[test]
+    fn gull_wing() {
+        // Use calculator from pcblibraries.com for validation
+        let pad_props = Ipc7351B::new(PackageType::GullWing)
+            .pitch(0.5)
+            .body(7.0)
+            .lead_span(Range(8.8, 9.2))
+            .lead_width(Range(0.17, 0.27))
+            .lead_len(Range(0.45, 0.75))
+            .settings(&load_config!("../qeda.yml"))
+            .calc();
+
+        // Zmax = 9.92, Gmin = 6.80 as from the calculator, then the gap is widened to the body
+        assert_eq!(pad_props.distance, 8.42);
+        assert_eq!(pad_props.size.x, 1.51);
+        assert_eq!(pad_props.size.y, 0.28);
+        assert_eq!(pad_props.courtyard, 0.25);
+    }
+
+    #[test]
+    fn flat_lug() {
+        let pad_props = Ipc7351B::new(PackageType::FlatLug)
+            .lead_span(Range(9.7, 10.1))
+            .lead_width(Range(5.2, 5.5))
+            .lead_len(Range(1.0, 1.5))
+            .settings(&load_config!("../qeda.yml"))
+            .calc();
+
+        assert_eq!(values(&pad_props), [8.66, 2.36, 5.52, 0.25]);
+    }
+
+    #[test]
+    fn molded() {
+        // SMA diode
+        let pad_props = Ipc7351B::new(PackageType::Molded)
+            .lead_span(Range(4.8, 5.28))
+            .lead_width(Range(1.25, 1.65))
+            .lead_len(Range(0.76, 1.52))
+            .settings(&load_config!("../qeda.yml"))
+            .calc();
+
+        assert_eq!(values(&pad_props), [3.38, 2.21, 1.57, 0.25]);
+    }
+
+    #[test]
+    fn cae() {
+        // 6.3 mm can
+        let pad_props = Ipc7351B::new(PackageType::Cae)
+            .lead_span(Range(7.0, 7.6))
+            .lead_width(Range(0.5, 0.8))
+            .lead_len(Range(1.0, 1.6))
+            .settings(&load_config!("../qeda.yml"))
+            .calc();
+
+        assert_eq!(values(&pad_props), [6.5, 2.12, 1.42, 0.5]);
+    }
+
+    #[test]
+    fn chip_array() {
+        // 0603 x 4 array
+        let ipc = |package_type| {
+            Ipc7351B::new(package_type)
+                .pitch(0.8)
+                .lead_span(Range(1.5, 1.7))
+                .lead_width(Range(0.3, 0.5))
+                .lead_len(Range(0.2, 0.4))
+                .settings(&load_config!("../qeda.yml"))
+                .calc()
+        };
+
+        assert_eq!(
+            values(&ipc(PackageType::ConcaveArray)),
+            [1.78, 0.84, 0.53, 0.2]
+        );
+        assert_eq!(
+            values(&ipc(PackageType::ConvexArray)),
+            [1.78, 0.84, 0.39, 0.2]
+        );
+    }
+
+    #[test]
+    fn melf() {
+        // MiniMELF
+        let pad_props = Ipc7351B::new(PackageType::Melf)
+            .lead_span(Range(3.3, 3.7))
+            .lead_width(Range(1.4, 1.6))
+            .lead_len(Range(0.25, 0.5))
+            .settings(&load_config!("../qeda.yml"))
+            .calc();
+
+        assert_eq!(values(&pad_props), [3.4, 1.12, 1.73, 0.2]);
+    }
+
+    #[test]
+    fn courtyard_only() {
+        // Lands of these packages are not calculated from goals
+        let lib_cfg = load_config!("../qeda.yml");
+        let courtyard = |package_type| Ipc7351B::new(package_type).settings(&lib_cfg).courtyard();
+        assert_eq!(courtyard(PackageType::Bga), 1.0);
+        assert_eq!(courtyard(PackageType::ThroughHole), 0.25);
+        assert_eq!(courtyard(PackageType::Unknown), 0.25);
+    }
 }
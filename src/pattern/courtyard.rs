@@ -0,0 +1,121 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Layer, Pad, Rect};
+use crate::packages::PackageType;
+
+use super::Ipc7351B;
+
+/// Draws a courtyard around pads and body expanded by `excess`.
+///
+/// The outline is rounded outwards to `pattern.grid.courtyard`. Parts having copper on the
+/// bottom side only get the courtyard on the bottom side.
+pub fn draw(
+    drawing: &mut Drawing,
+    pads: &[Pad],
+    body: Option<&Rect>,
+    excess: f64,
+    lib_cfg: &Config,
+) {
+    let mut bounds = body.map(|b| (b.p.0.x, b.p.0.y, b.p.1.x, b.p.1.y));
+    for pad in pads {
+        let size = pad.extent();
+        let (x, y) = (pad.origin.x, pad.origin.y);
+        let (hx, hy) = (size.x / 2.0, size.y / 2.0);
+        bounds = Some(match bounds {
+            Some((x0, y0, x1, y1)) => (
+                x0.min(x - hx),
+                y0.min(y - hy),
+                x1.max(x + hx),
+                y1.max(y + hy),
+            ),
+            None => (x - hx, y - hy, x + hx, y + hy),
+        });
+    }
+    let (x0, y0, x1, y1) = match bounds {
+        Some(bounds) => bounds,
+        None => return,
+    };
+
+    let grid = lib_cfg.get_f64("pattern.grid.courtyard").unwrap();
+    let floor = |v: f64| (v / grid + 1e-6).floor() * grid;
+    let ceil = |v: f64| (v / grid - 1e-6).ceil() * grid;
+    let rect = Rect::new(
+        floor(x0 - excess),
+        floor(y0 - excess),
+        ceil(x1 + excess),
+        ceil(y1 + excess),
+    );
+
+    let bottom = pads.iter().all(|p| !p.layers.contains(Layer::COPPER_TOP))
+        && pads.iter().any(|p| p.layers.contains(Layer::COPPER_BOTTOM));
+    let layer = if bottom {
+        Layer::COURTYARD_BOTTOM
+    } else {
+        Layer::COURTYARD_TOP
+    };
+    let line_width = lib_cfg.get_f64("pattern.line-width.courtyard").unwrap();
+    drawing.add_lines(rect.line_width(line_width).layer(layer).to_lines());
+}
+
+/// Returns the courtyard excess for the package type and the density level, used when pads
+/// are not calculated from IPC goals.
+pub fn excess(package_type: PackageType, lib_cfg: &Config) -> f64 {
+    Ipc7351B::new(package_type).settings(lib_cfg).courtyard()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::{Element, Line};
+
+    // Get courtyard lines around a single pad
+    fn courtyard(pad: Pad, excess: f64) -> Vec<Line> {
+        let mut drawing = Drawing::new();
+        draw(
+            &mut drawing,
+            &[pad],
+            None,
+            excess,
+            &load_config!("../qeda.yml"),
+        );
+        drawing
+            .elements
+            .into_iter()
+            .filter_map(|e| match e {
+                Element::Line(line) => Some(line),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn grid() {
+        // Pad edges at -0.59, 0.61 and ±0.35 are expanded by 0.25 and rounded outwards to 0.05
+        let pad = Pad::new("1")
+            .size(1.2, 0.7)
+            .origin(0.01, 0.0)
+            .layers(Layer::COPPER_TOP);
+        let lines = courtyard(pad, 0.25);
+        let xs = lines.iter().flat_map(|l| [l.p.0.x, l.p.1.x]);
+        let ys = lines.iter().flat_map(|l| [l.p.0.y, l.p.1.y]);
+        let (x0, x1) = xs.fold((0.0, 0.0), |(a, b), x| (f64::min(a, x), f64::max(b, x)));
+        let (y0, y1) = ys.fold((0.0, 0.0), |(a, b), y| (f64::min(a, y), f64::max(b, y)));
+        assert!((x0 + 0.85).abs() < 1e-9 && (x1 - 0.9).abs() < 1e-9);
+        assert!((y0 + 0.6).abs() < 1e-9 && (y1 - 0.6).abs() < 1e-9);
+        assert!(lines.iter().all(|l| l.layer == Layer::COURTYARD_TOP));
+    }
+
+    #[test]
+    fn bottom() {
+        let pad = Pad::new("1").size(1.0, 1.0);
+        let lines = courtyard(pad.clone().layers(Layer::COPPER_BOTTOM), 0.25);
+        assert!(lines.iter().all(|l| l.layer == Layer::COURTYARD_BOTTOM));
+
+        let both = Layer::COPPER_TOP | Layer::COPPER_BOTTOM;
+        let lines = courtyard(pad.clone().layers(both), 0.25);
+        assert!(lines.iter().all(|l| l.layer == Layer::COURTYARD_TOP));
+
+        // Holes without copper do not make the courtyard bottom
+        let lines = courtyard(pad.non_plated(1.0), 0.25);
+        assert!(lines.iter().all(|l| l.layer == Layer::COURTYARD_TOP));
+    }
+}
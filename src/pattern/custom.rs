@@ -0,0 +1,95 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::packages::PackageType;
+
+use super::{assembly, courtyard, mask, silkscreen};
+
+/// Free-form pattern made of explicitly specified pads.
+#[derive(Debug, Default)]
+pub struct Custom {
+    pads: Vec<Pad>,
+    body: Option<Rect>,
+    courtyard: Option<f64>,
+}
+
+impl Custom {
+    /// Builds a `Custom` with a body outline.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body = Some(Rect::new(
+            -width / 2.0,
+            -height / 2.0,
+            width / 2.0,
+            height / 2.0,
+        ));
+        self
+    }
+
+    /// Builds a `Custom` with modified courtyard excess around pads and body.
+    ///
+    /// The default excess depends on the density level.
+    pub fn courtyard(mut self, courtyard: f64) -> Self {
+        self.courtyard = Some(courtyard);
+        self
+    }
+
+    /// Draws custom pattern.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let mut pads = self.pads.clone();
+        mask::calc(&mut pads, lib_cfg);
+
+        if let Some(body) = &self.body {
+            silkscreen::draw_body(drawing, body, &pads, lib_cfg);
+            assembly::draw_body(drawing, body, None, lib_cfg);
+        } else if let Some(outline) = outline(&pads, lib_cfg) {
+            // Outline is clear of pads already, so it is not clipped
+            let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+            let rect = outline
+                .expand(line_width / 2.0)
+                .line_width(line_width)
+                .layer(Layer::SILKSCREEN_TOP);
+            drawing.add_lines(rect.to_lines());
+        }
+
+        let excess = self
+            .courtyard
+            .unwrap_or_else(|| courtyard::excess(PackageType::Unknown, lib_cfg));
+        courtyard::draw(drawing, &pads, self.body.as_ref(), excess, lib_cfg);
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `Custom` with modified pads.
+    pub fn pads(mut self, pads: Vec<Pad>) -> Self {
+        self.pads = pads;
+        self
+    }
+}
+
+// Get a rectangle around pads keeping the silkscreen clearance
+fn outline(pads: &[Pad], lib_cfg: &Config) -> Option<Rect> {
+    let clearance = lib_cfg
+        .get_f64("pattern.clearance.pad-to-silkscreen")
+        .unwrap();
+    pads.iter()
+        .map(|pad| {
+            let size = pad.extent();
+            let (hx, hy) = (size.x / 2.0, size.y / 2.0);
+            let space = clearance + pad.mask.max(0.0);
+            Rect::new(
+                pad.origin.x - hx - space,
+                pad.origin.y - hy - space,
+                pad.origin.x + hx + space,
+                pad.origin.y + hy + space,
+            )
+        })
+        .reduce(|a, b| {
+            Rect::new(
+                a.p.0.x.min(b.p.0.x),
+                a.p.0.y.min(b.p.0.y),
+                a.p.1.x.max(b.p.1.x),
+                a.p.1.y.max(b.p.1.y),
+            )
+        })
+}
@@ -0,0 +1,234 @@
+use std::f64::consts::PI;
+
+use crate::config::Config;
+use crate::drawing::*;
+use crate::packages::PackageType;
+
+use super::{assembly, courtyard, mask, silkscreen};
+
+/// Pattern of a cylindrical through-hole package (TO-92, TO-5, TO-18).
+#[derive(Debug, Default)]
+pub struct Cylindrical {
+    pad: Pad,
+    pitch: f64,
+    lead_count: usize,
+    stagger: f64,
+    lead_circle: Option<(f64, f64)>,
+    diameter: f64,
+    flat: Option<f64>,
+    polarity_mark: Option<String>,
+}
+
+impl Cylindrical {
+    /// Builds a `Cylindrical` with modified body diameter.
+    ///
+    /// If `flat` is specified, the body has a flat side at that distance below the center.
+    pub fn body(mut self, diameter: f64, flat: Option<f64>) -> Self {
+        self.diameter = diameter;
+        self.flat = flat;
+        self
+    }
+
+    /// Draws cylindrical pattern.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let positions: Vec<(f64, f64)> = match self.lead_circle {
+            Some((diameter, angle)) => (0..self.lead_count)
+                .map(|i| {
+                    let a = PI - (angle * i as f64).to_radians();
+                    (diameter / 2.0 * a.cos(), diameter / 2.0 * a.sin())
+                })
+                .collect(),
+            None => {
+                let x0 = -self.pitch * (self.lead_count as f64 - 1.0) / 2.0;
+                (0..self.lead_count)
+                    .map(|i| {
+                        let y = if i % 2 == 1 { self.stagger } else { 0.0 };
+                        (x0 + self.pitch * i as f64, y)
+                    })
+                    .collect()
+            }
+        };
+        let spacing = positions
+            .windows(2)
+            .map(|p| (p[1].0 - p[0].0).hypot(p[1].1 - p[0].1))
+            .fold(f64::MAX, f64::min);
+        let pad = super::fit_pitch(self.pad.clone(), spacing, true, lib_cfg);
+
+        let mut pads = Vec::with_capacity(self.lead_count);
+        for (i, (x, y)) in positions.into_iter().enumerate() {
+            let mut p = pad.clone().name(&(i + 1).to_string()).origin(x, y);
+            if i == 0 {
+                p = p.shape(PadShape::Rect);
+            }
+            pads.push(p);
+        }
+        mask::calc(&mut pads, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let radius = (self.diameter + line_width) / 2.0;
+        match self.flat {
+            Some(flat) => {
+                let lines = flat_outline(radius, flat + line_width / 2.0)
+                    .into_iter()
+                    .map(|l| l.width(line_width).layer(Layer::SILKSCREEN_TOP))
+                    .collect();
+                silkscreen::draw_clipped(drawing, lines, &pads, lib_cfg);
+            }
+            None => silkscreen::draw_circle(drawing, Point::new(0.0, 0.0), radius, &pads, lib_cfg),
+        }
+        if let Some(mark) = &self.polarity_mark {
+            silkscreen::draw_pin1_mark(drawing, mark, &pads[0], &pads, lib_cfg);
+        }
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+        let radius = self.diameter / 2.0;
+        match self.flat {
+            Some(flat) => drawing.add_lines(
+                flat_outline(radius, flat)
+                    .into_iter()
+                    .map(|l| l.width(line_width).layer(Layer::ASSEMBLY_TOP))
+                    .collect(),
+            ),
+            None => drawing.add_circle(
+                Circle::new(0.0, 0.0, radius)
+                    .width(line_width)
+                    .layer(Layer::ASSEMBLY_TOP),
+            ),
+        }
+
+        // Pin 1 dot is inside the body edge in the direction of pin 1
+        let size = lib_cfg.get_f64("pattern.polarity.size").unwrap();
+        let pin1 = &pads[0].origin;
+        let distance = pin1.x.hypot(pin1.y);
+        if distance > 0.0 {
+            let k = (radius - size) / distance;
+            assembly::draw_dot(drawing, Point::new(pin1.x * k, pin1.y * k), lib_cfg);
+        }
+        let d = radius / 2f64.sqrt();
+        assembly::draw_ref_des(drawing, &Rect::new(-d, -d, d, d), lib_cfg);
+
+        let body = Rect::new(-radius, -radius, radius, radius);
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&body),
+            courtyard::excess(PackageType::ThroughHole, lib_cfg),
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `Cylindrical` with leads on a circle of the specified diameter.
+    ///
+    /// Pin 1 is the left one, next leads follow counter-clockwise with the specified angle
+    /// (in degrees) between them.
+    pub fn lead_circle(mut self, diameter: f64, angle: f64) -> Self {
+        self.lead_circle = Some((diameter, angle));
+        self
+    }
+
+    /// Builds a `Cylindrical` with modified lead count.
+    pub fn lead_count(mut self, lead_count: usize) -> Self {
+        self.lead_count = lead_count;
+        self
+    }
+
+    /// Builds a `Cylindrical` with modified template pad.
+    pub fn pad(mut self, pad: Pad) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    /// Builds a `Cylindrical` with modified pitch of inline leads.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `Cylindrical` with a polarity mark near pin 1.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+
+    /// Builds a `Cylindrical` with even inline leads shifted along the y-axis by `stagger`.
+    pub fn stagger(mut self, stagger: f64) -> Self {
+        self.stagger = stagger;
+        self
+    }
+}
+
+// Get lines of a circle outline cut by a flat side at the distance `flat` below the center
+fn flat_outline(radius: f64, flat: f64) -> Vec<Line> {
+    let flat = flat.min(radius);
+    let a0 = (flat / radius).asin();
+    let a1 = PI - a0;
+    let count = 48;
+    let point = |i: usize| {
+        let a = a1 + (2.0 * PI - (a1 - a0)) * i as f64 / count as f64;
+        (radius * a.cos(), radius * a.sin())
+    };
+    let mut lines: Vec<Line> = (0..count)
+        .map(|i| {
+            let (x0, y0) = point(i);
+            let (x1, y1) = point(i + 1);
+            Line::new(x0, y0, x1, y1)
+        })
+        .collect();
+    let x = radius * a0.cos();
+    lines.push(Line::new(-x, flat, x, flat));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Get pad origins of a drawn pattern rounded off to avoid floating point noise
+    fn origins(cylindrical: &Cylindrical) -> Vec<(f64, f64)> {
+        let mut drawing = Drawing::new();
+        cylindrical.draw(&mut drawing, &load_config!("../qeda.yml"));
+        let round = |v: f64| (v * 1e6).round() / 1e6;
+        drawing
+            .elements
+            .into_iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some((round(pad.origin.x), round(pad.origin.y))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn layout() {
+        let pad = Pad::new("")
+            .hole(0.7, 0.7)
+            .size(1.2, 1.2)
+            .layers(Layer::COPPER_TOP | Layer::COPPER_BOTTOM);
+
+        // TO-92 with inline leads
+        let cylindrical = Cylindrical::default()
+            .pad(pad.clone())
+            .pitch(1.27)
+            .lead_count(3)
+            .body(4.8, Some(1.6));
+        assert_eq!(
+            origins(&cylindrical),
+            [(-1.27, 0.0), (0.0, 0.0), (1.27, 0.0)]
+        );
+
+        // TO-5 with leads on a circle, counter-clockwise from the left one
+        let cylindrical = Cylindrical::default()
+            .pad(pad)
+            .lead_count(3)
+            .lead_circle(5.08, 90.0)
+            .body(9.0, None);
+        assert_eq!(
+            origins(&cylindrical),
+            [(-2.54, 0.0), (0.0, 2.54), (2.54, 0.0)]
+        );
+    }
+}
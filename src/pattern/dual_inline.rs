@@ -0,0 +1,158 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::packages::PackageType;
+
+use super::{assembly, courtyard, mask, silkscreen};
+
+/// Through-hole pattern with two rows of leads.
+#[derive(Debug, Default)]
+pub struct DualInline {
+    pad: Pad,
+    pitch: f64,
+    row_span: f64,
+    lead_count: usize,
+    body: Rect,
+    polarity_mark: Option<String>,
+}
+
+impl DualInline {
+    /// Builds a `DualInline` with modified body.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self
+    }
+
+    /// Draws dual inline pattern.
+    ///
+    /// Pin 1 pad is square. Other pads keep the shape of the template pad; round pads
+    /// which do not fit the pitch become oblong.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let pad = super::fit_pitch(self.pad.clone(), self.pitch, false, lib_cfg);
+
+        let row_count = self.lead_count / 2;
+        let x = self.row_span / 2.0;
+        let y0 = -self.pitch * (row_count as f64 - 1.0) / 2.0;
+        let mut pads = Vec::with_capacity(self.lead_count);
+        for i in 0..self.lead_count {
+            let (x, y) = if i < row_count {
+                (-x, y0 + self.pitch * i as f64)
+            } else {
+                (x, y0 + self.pitch * (self.lead_count - i - 1) as f64)
+            };
+            let mut p = pad.clone().name(&(i + 1).to_string()).origin(x, y);
+            if i == 0 {
+                p = p.shape(PadShape::Rect);
+            }
+            pads.push(p);
+        }
+
+        mask::calc(&mut pads, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let lines = self
+            .body
+            .clone()
+            .expand(line_width / 2.0)
+            .line_width(line_width)
+            .layer(Layer::SILKSCREEN_TOP)
+            .to_lines();
+        silkscreen::draw_clipped(drawing, lines, &pads, lib_cfg);
+
+        let pin1 = pads.iter().find(|p| p.name == "1");
+        if let (Some(mark), Some(pin1)) = (&self.polarity_mark, pin1) {
+            silkscreen::draw_pin1_mark(drawing, mark, pin1, &pads, lib_cfg);
+        }
+        assembly::draw_body(drawing, &self.body, pin1, lib_cfg);
+
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&self.body),
+            courtyard::excess(PackageType::ThroughHole, lib_cfg),
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `DualInline` with modified lead count (for both rows).
+    ///
+    /// Pin 1 is the top one in the left row. Numbering goes downwards along the left row
+    /// and then upwards along the right one.
+    pub fn lead_count(mut self, lead_count: usize) -> Self {
+        self.lead_count = lead_count;
+        self
+    }
+
+    /// Builds a `DualInline` with modified template pad.
+    pub fn pad(mut self, pad: Pad) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    /// Builds a `DualInline` with modified pitch.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `DualInline` with a polarity mark near pin 1.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+
+    /// Builds a `DualInline` with modified distance between lead rows.
+    pub fn row_span(mut self, row_span: f64) -> Self {
+        self.row_span = row_span;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbering() {
+        let pad = Pad::new("")
+            .hole(0.8, 0.8)
+            .size(1.2, 1.2)
+            .layers(Layer::COPPER_TOP | Layer::COPPER_BOTTOM);
+        let dual_inline = DualInline::default()
+            .pad(pad)
+            .pitch(2.54)
+            .row_span(7.62)
+            .lead_count(8);
+        let mut drawing = Drawing::new();
+        dual_inline.draw(&mut drawing, &load_config!("../qeda.yml"));
+        let pads: Vec<_> = drawing
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect();
+        let origin = |name: &str| {
+            let pad = pads.iter().find(|p| p.name == name).unwrap();
+            (pad.origin.x, pad.origin.y)
+        };
+
+        // Down the left row and up the right one
+        assert_eq!(pads.len(), 8);
+        assert_eq!(origin("1"), (-3.81, -3.81));
+        assert_eq!(origin("4"), (-3.81, 3.81));
+        assert_eq!(origin("5"), (3.81, 3.81));
+        assert_eq!(origin("8"), (3.81, -3.81));
+
+        // Pin 1 is square
+        assert!(matches!(pads[0].shape, PadShape::Rect));
+        assert!(matches!(pads[1].shape, PadShape::Circle));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+use super::{assembly, courtyard, mask, silkscreen, PadProperties};
+
+#[derive(Debug, Default)]
+pub struct DualRow {
+    pad_props: PadProperties,
+    pitch: f64,
+    left: Vec<Option<String>>,
+    right: Vec<Option<String>>,
+    body: Rect,
+    exposed_pad: Option<Pad>,
+    polarity_mark: Option<String>,
+}
+
+impl DualRow {
+    /// Builds a `DualRow` with modified body.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self
+    }
+
+    /// Draws dual row pattern.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let mut pad_props = self.pad_props.clone();
+        if let Some(exposed_pad) = &self.exposed_pad {
+            let clearance = lib_cfg.get_f64("pattern.clearance.pad-to-pad").unwrap();
+            let min_len = lib_cfg.get_f64("pattern.minimum.pad-length").unwrap();
+            if !pad_props.trim_inner(exposed_pad.size.x + 2.0 * clearance, min_len) {
+                warn!("pads are too near to the exposed pad");
+            }
+        }
+
+        let x = pad_props.distance / 2.0;
+        let pad = Pad::new("")
+            .shape(PadShape::Rect)
+            .size(pad_props.size.x, pad_props.size.y)
+            .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP);
+
+        let mut pads = Vec::with_capacity(self.left.len() + self.right.len());
+        for (x, row) in &[(-x, &self.left), (x, &self.right)] {
+            let y0 = -self.pitch * (row.len() as f64 - 1.0) / 2.0;
+            for (i, name) in row.iter().enumerate() {
+                if let Some(name) = name {
+                    let y = y0 + self.pitch * i as f64;
+                    pads.push(pad.clone().name(name).origin(*x, y));
+                }
+            }
+        }
+
+        if let Some(exposed_pad) = &self.exposed_pad {
+            pads.push(exposed_pad.clone());
+        }
+
+        mask::calc(&mut pads, lib_cfg);
+        silkscreen::draw_body(drawing, &self.body, &pads, lib_cfg);
+
+        let pin1 = pads.iter().find(|p| p.name == "1");
+        if let (Some(mark), Some(pin1)) = (&self.polarity_mark, pin1) {
+            silkscreen::draw_pin1_mark(drawing, mark, pin1, &pads, lib_cfg);
+        }
+        assembly::draw_body(drawing, &self.body, pin1, lib_cfg);
+
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&self.body),
+            self.pad_props.courtyard,
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `DualRow` with an exposed pad in the center.
+    pub fn exposed_pad(mut self, pad: Pad) -> Self {
+        self.exposed_pad = Some(pad);
+        self
+    }
+
+    /// Builds a `DualRow` with modified lead count (for both rows).
+    ///
+    /// Pin 1 is the top one in the left row. Numbering goes downwards along the left row
+    /// and then upwards along the right one.
+    pub fn lead_count(mut self, lead_count: usize) -> Self {
+        let row_count = lead_count / 2;
+        self.left = (1..=row_count).map(|i| Some(i.to_string())).collect();
+        self.right = (row_count + 1..=lead_count)
+            .rev()
+            .map(|i| Some(i.to_string()))
+            .collect();
+        self
+    }
+
+    /// Builds a `DualRow` with an arbitrary lead layout.
+    ///
+    /// Each row is a list of pad names from top to bottom, `None` stands for a missing lead.
+    pub fn lead_layout(mut self, left: Vec<Option<String>>, right: Vec<Option<String>>) -> Self {
+        self.left = left;
+        self.right = right;
+        self
+    }
+
+    /// Builds a `DualRow` with modified pad properties.
+    pub fn pad_properties(mut self, pad_props: PadProperties) -> Self {
+        self.pad_props = pad_props;
+        self
+    }
+
+    /// Builds a `DualRow` with modified pitch.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `DualRow` with a polarity mark near pin 1.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+}
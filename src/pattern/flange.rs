@@ -0,0 +1,183 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::packages::PackageType;
+
+use super::{assembly, courtyard, mask, silkscreen};
+
+/// Pattern of a flange-mount through-hole package (TO-220, TO-247) standing vertically.
+///
+/// Leads are in a row along the x-axis, the tab is at the back (top) side of the body.
+#[derive(Debug, Default)]
+pub struct Flange {
+    pad: Pad,
+    pitch: f64,
+    lead_count: usize,
+    stagger: f64,
+    body: Rect,
+    tab: f64,
+    polarity_mark: Option<String>,
+}
+
+impl Flange {
+    /// Builds a `Flange` with modified body shifted along the y-axis by `offset`.
+    pub fn body(mut self, width: f64, height: f64, offset: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = offset - height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = offset + height / 2.0;
+        self
+    }
+
+    /// Draws flange pattern.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        // Adjacent leads of a staggered package are in different rows
+        let spacing = if self.stagger > 0.0 {
+            self.pitch.hypot(self.stagger).min(2.0 * self.pitch)
+        } else {
+            self.pitch
+        };
+        let pad = super::fit_pitch(self.pad.clone(), spacing, true, lib_cfg);
+
+        let x0 = -self.pitch * (self.lead_count as f64 - 1.0) / 2.0;
+        let mut pads = Vec::with_capacity(self.lead_count);
+        for i in 0..self.lead_count {
+            let y = if i % 2 == 1 { self.stagger } else { 0.0 };
+            let mut p = pad
+                .clone()
+                .name(&(i + 1).to_string())
+                .origin(x0 + self.pitch * i as f64, y);
+            if i == 0 {
+                p = p.shape(PadShape::Rect);
+            }
+            pads.push(p);
+        }
+        mask::calc(&mut pads, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let lines = self
+            .body
+            .clone()
+            .expand(line_width / 2.0)
+            .line_width(line_width)
+            .layer(Layer::SILKSCREEN_TOP)
+            .to_lines();
+        silkscreen::draw_clipped(drawing, lines, &pads, lib_cfg);
+        if let Some(mark) = &self.polarity_mark {
+            silkscreen::draw_pin1_mark(drawing, mark, &pads[0], &pads, lib_cfg);
+        }
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+        let body = self.body.clone();
+        drawing.add_lines(
+            body.clone()
+                .line_width(line_width)
+                .layer(Layer::ASSEMBLY_TOP)
+                .to_lines(),
+        );
+        if self.tab > 0.0 {
+            let y = body.p.0.y + self.tab;
+            drawing.add_line(
+                Line::new(body.p.0.x, y, body.p.1.x, y)
+                    .width(line_width)
+                    .layer(Layer::ASSEMBLY_TOP),
+            );
+        }
+        assembly::draw_ref_des(drawing, &body, lib_cfg);
+
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&self.body),
+            courtyard::excess(PackageType::ThroughHole, lib_cfg),
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `Flange` with modified lead count.
+    pub fn lead_count(mut self, lead_count: usize) -> Self {
+        self.lead_count = lead_count;
+        self
+    }
+
+    /// Builds a `Flange` with modified template pad.
+    pub fn pad(mut self, pad: Pad) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    /// Builds a `Flange` with modified pitch.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `Flange` with a polarity mark near pin 1.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+
+    /// Builds a `Flange` with even leads shifted along the y-axis by `stagger`.
+    pub fn stagger(mut self, stagger: f64) -> Self {
+        self.stagger = stagger;
+        self
+    }
+
+    /// Builds a `Flange` with a tab of the specified thickness.
+    pub fn tab(mut self, thickness: f64) -> Self {
+        self.tab = thickness;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Get pad origins of a drawn pattern rounded off to avoid floating point noise
+    fn origins(flange: &Flange) -> Vec<(f64, f64)> {
+        let mut drawing = Drawing::new();
+        flange.draw(&mut drawing, &load_config!("../qeda.yml"));
+        let round = |v: f64| (v * 1e6).round() / 1e6;
+        drawing
+            .elements
+            .into_iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some((round(pad.origin.x), round(pad.origin.y))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn layout() {
+        let pad = Pad::new("")
+            .hole(1.1, 1.1)
+            .size(1.8, 1.8)
+            .layers(Layer::COPPER_TOP | Layer::COPPER_BOTTOM);
+
+        // TO-220
+        let flange = Flange::default()
+            .pad(pad.clone())
+            .pitch(2.54)
+            .lead_count(3)
+            .body(10.0, 4.5, -1.0);
+        assert_eq!(origins(&flange), [(-2.54, 0.0), (0.0, 0.0), (2.54, 0.0)]);
+
+        // TO-220-5 with staggered leads
+        let flange = Flange::default()
+            .pad(pad)
+            .pitch(1.7)
+            .lead_count(5)
+            .stagger(3.7)
+            .body(10.0, 4.5, -1.0);
+        assert_eq!(
+            origins(&flange),
+            [(-3.4, 0.0), (-1.7, 3.7), (0.0, 0.0), (1.7, 3.7), (3.4, 0.0)]
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::packages::PackageType;
+use crate::pinout::Pinout;
+
+use super::{assembly, courtyard, mask, silkscreen};
+
+#[derive(Debug, Default)]
+pub struct GridArray {
+    pitch: Size,
+    row_count: usize,
+    column_count: usize,
+    land: f64,
+    mask_defined: bool,
+    depopulated: Vec<String>,
+    body: Rect,
+    polarity_mark: Option<String>,
+}
+
+impl GridArray {
+    /// Builds a `GridArray` with modified body.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self
+    }
+
+    /// Builds a `GridArray` with modified numbers of rows and columns.
+    pub fn counts(mut self, row_count: usize, column_count: usize) -> Self {
+        self.row_count = row_count;
+        self.column_count = column_count;
+        self
+    }
+
+    /// Builds a `GridArray` with missing balls.
+    pub fn depopulated(mut self, numbers: Vec<String>) -> Self {
+        self.depopulated = numbers;
+        self
+    }
+
+    /// Draws grid array pattern.
+    ///
+    /// Ball `A1` is the top left one. Rows are named by JEDEC letters from top to bottom,
+    /// columns are numbered from left to right.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let mask = lib_cfg.get_f64("pattern.clearance.pad-to-mask").unwrap();
+        // Solder mask defined pads have copper under the mask edge
+        let diameter = if self.mask_defined {
+            self.land + 2.0 * mask
+        } else {
+            self.land
+        };
+
+        let pinout = Pinout::new();
+        let x0 = -self.pitch.x * (self.column_count as f64 - 1.0) / 2.0;
+        let y0 = -self.pitch.y * (self.row_count as f64 - 1.0) / 2.0;
+        let pad = Pad::new("")
+            .shape(PadShape::Circle)
+            .size(diameter, diameter)
+            .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP);
+
+        let mut pads = Vec::new();
+        for row in 0..self.row_count {
+            let row_name = pinout.row_name(row).unwrap_or_default();
+            for col in 0..self.column_count {
+                let name = format!("{}{}", row_name, col + 1);
+                if self.depopulated.contains(&name) {
+                    continue;
+                }
+                let x = x0 + self.pitch.x * col as f64;
+                let y = y0 + self.pitch.y * row as f64;
+                pads.push(pad.clone().name(&name).origin(x, y));
+            }
+        }
+
+        mask::calc(&mut pads, lib_cfg);
+        if self.mask_defined {
+            for pad in pads.iter_mut() {
+                pad.mask = -mask;
+            }
+        }
+        let outline = self.outline(Layer::SILKSCREEN_TOP, lib_cfg);
+        silkscreen::draw_clipped(drawing, outline, &pads, lib_cfg);
+        let pin1 = pads.iter().find(|p| p.name == "A1");
+        if let (Some(mark), Some(pin1)) = (&self.polarity_mark, pin1) {
+            silkscreen::draw_pin1_mark(drawing, mark, pin1, &pads, lib_cfg);
+        }
+        drawing.add_lines(self.outline(Layer::ASSEMBLY_TOP, lib_cfg));
+
+        assembly::draw_ref_des(drawing, &self.body, lib_cfg);
+
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&self.body),
+            courtyard::excess(PackageType::Bga, lib_cfg),
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `GridArray` with modified land diameter.
+    ///
+    /// If `mask_defined` is `true`, the land is defined by the solder mask opening (SMD),
+    /// otherwise by the copper (NSMD).
+    pub fn land(mut self, diameter: f64, mask_defined: bool) -> Self {
+        self.land = diameter;
+        self.mask_defined = mask_defined;
+        self
+    }
+
+    /// Builds a `GridArray` with modified pitches between columns (`x`) and rows (`y`).
+    pub fn pitch(mut self, x: f64, y: f64) -> Self {
+        self.pitch = Size::new(x, y);
+        self
+    }
+
+    /// Builds a `GridArray` with a polarity mark near ball `A1`.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+
+    // Get the body outline with a chamfered A1 corner
+    fn outline(&self, layer: Layer, lib_cfg: &Config) -> Vec<Line> {
+        let (line_width, rect) = if layer == Layer::SILKSCREEN_TOP {
+            let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+            (line_width, self.body.clone().expand(line_width / 2.0))
+        } else {
+            let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+            (line_width, self.body.clone())
+        };
+        let (x0, y0, x1, y1) = (rect.p.0.x, rect.p.0.y, rect.p.1.x, rect.p.1.y);
+        let chamfer = ((x1 - x0).min(y1 - y0) / 4.0).min(1.0);
+
+        let points = [
+            (x0 + chamfer, y0),
+            (x1, y0),
+            (x1, y1),
+            (x0, y1),
+            (x0, y0 + chamfer),
+            (x0 + chamfer, y0),
+        ];
+        points
+            .windows(2)
+            .map(|p| {
+                Line::new(p[0].0, p[0].1, p[1].0, p[1].1)
+                    .width(line_width)
+                    .layer(layer)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Get pads of a drawn pattern
+    fn pads(grid_array: &GridArray) -> Vec<Pad> {
+        let mut drawing = Drawing::new();
+        grid_array.draw(&mut drawing, &load_config!("../qeda.yml"));
+        drawing
+            .elements
+            .into_iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn grid() {
+        let grid_array = GridArray::default()
+            .pitch(1.0, 0.8)
+            .counts(3, 4)
+            .land(0.5, false)
+            .depopulated(vec!["B2".to_string(), "C4".to_string()]);
+        let pads = pads(&grid_array);
+        let names: Vec<_> = pads.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["A1", "A2", "A3", "A4", "B1", "B3", "B4", "C1", "C2", "C3"]
+        );
+
+        let origin = |name: &str| {
+            let pad = pads.iter().find(|p| p.name == name).unwrap();
+            (pad.origin.x, pad.origin.y)
+        };
+        assert_eq!(origin("A1"), (-1.5, -0.8));
+        assert_eq!(origin("B3"), (0.5, 0.0));
+        assert_eq!(origin("C3"), (0.5, 0.8));
+    }
+
+    #[test]
+    fn lands() {
+        // Copper defined land has the mask opening around it
+        let grid_array = GridArray::default()
+            .pitch(1.0, 1.0)
+            .counts(2, 2)
+            .land(0.5, false);
+        for pad in pads(&grid_array) {
+            assert_eq!((pad.size.x, pad.size.y), (0.5, 0.5));
+            assert_eq!(pad.mask, 0.05);
+        }
+
+        // Mask defined land has copper under the mask edge
+        let grid_array = GridArray::default()
+            .pitch(1.0, 1.0)
+            .counts(2, 2)
+            .land(0.5, true);
+        for pad in pads(&grid_array) {
+            assert!((pad.size.x - 0.6).abs() < 1e-9 && pad.size.x == pad.size.y);
+            assert_eq!(pad.mask, -0.05);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::packages::PackageType;
+
+use super::{assembly, courtyard, mask, silkscreen};
+
+/// Pattern of a single or dual row pin header.
+#[derive(Debug, Default)]
+pub struct Header {
+    pad: Pad,
+    pitch: f64,
+    row_span: f64,
+    row_count: usize,
+    lead_count: usize,
+    row_by_row: bool,
+    pegs: Vec<Pad>,
+    body: Rect,
+    shroud: Option<(f64, f64)>,
+    polarity_mark: Option<String>,
+}
+
+impl Header {
+    /// Builds a `Header` with modified body.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self
+    }
+
+    /// Builds a `Header` with modified lead count and row count (1 or 2).
+    pub fn counts(mut self, lead_count: usize, row_count: usize) -> Self {
+        self.lead_count = lead_count;
+        self.row_count = row_count;
+        self
+    }
+
+    /// Draws header pattern.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let row_len = self.lead_count / self.row_count;
+        let y0 = -self.pitch * (row_len as f64 - 1.0) / 2.0;
+        let mut pads = Vec::with_capacity(self.lead_count + self.pegs.len());
+        for i in 0..self.lead_count {
+            let (column, row) = if self.row_count > 1 && self.row_by_row {
+                (i / row_len, i % row_len)
+            } else {
+                (i % 2, i / self.row_count)
+            };
+            let x = if column == 0 {
+                -self.row_span / 2.0
+            } else {
+                self.row_span / 2.0
+            };
+            let y = y0 + self.pitch * row as f64;
+            let mut pad = self.pad.clone().name(&(i + 1).to_string()).origin(x, y);
+            if i == 0 && !pad.is_smd() {
+                pad = pad.shape(PadShape::Rect);
+            }
+            pads.push(pad);
+        }
+        pads.extend(self.pegs.iter().cloned());
+
+        mask::calc(&mut pads, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let body = self.body.clone().expand(line_width / 2.0);
+        let lines = match self.shroud {
+            Some((wall, key_width)) => {
+                let (x0, y0, x1, y1) = (body.p.0.x, body.p.0.y, body.p.1.x, body.p.1.y);
+                let key_y = key_width / 2.0;
+                let key_x = x0 + wall;
+                let mut lines = vec![
+                    Line::new(x0, y0, x1, y0),
+                    Line::new(x1, y0, x1, y1),
+                    Line::new(x1, y1, x0, y1),
+                    Line::new(x0, y1, x0, key_y),
+                    Line::new(x0, key_y, key_x, key_y),
+                    Line::new(key_x, key_y, key_x, -key_y),
+                    Line::new(key_x, -key_y, x0, -key_y),
+                    Line::new(x0, -key_y, x0, y0),
+                ];
+                let inner = body.clone().expand(-wall - line_width);
+                lines.append(&mut inner.to_lines());
+                lines
+                    .into_iter()
+                    .map(|l| l.width(line_width).layer(Layer::SILKSCREEN_TOP))
+                    .collect()
+            }
+            None => body
+                .line_width(line_width)
+                .layer(Layer::SILKSCREEN_TOP)
+                .to_lines(),
+        };
+        silkscreen::draw_clipped(drawing, lines, &pads, lib_cfg);
+
+        let pin1 = pads.iter().find(|p| p.name == "1");
+        if let (Some(mark), Some(pin1)) = (&self.polarity_mark, pin1) {
+            silkscreen::draw_pin1_mark(drawing, mark, pin1, &pads, lib_cfg);
+        }
+        assembly::draw_body(drawing, &self.body, pin1, lib_cfg);
+
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&self.body),
+            courtyard::excess(PackageType::ThroughHole, lib_cfg),
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `Header` with modified template pad.
+    pub fn pad(mut self, pad: Pad) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    /// Builds a `Header` with mounting pegs.
+    pub fn pegs(mut self, pegs: Vec<Pad>) -> Self {
+        self.pegs = pegs;
+        self
+    }
+
+    /// Builds a `Header` with modified pitch.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `Header` with a polarity mark near pin 1.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+
+    /// Builds a `Header` with modified numbering of a dual row header.
+    ///
+    /// By default odd pins are in the left row and even ones are in the right row
+    /// (zigzag). Otherwise the left row goes first and then the right one, both from top
+    /// to bottom.
+    pub fn row_by_row(mut self, row_by_row: bool) -> Self {
+        self.row_by_row = row_by_row;
+        self
+    }
+
+    /// Builds a `Header` with modified distance between pad columns.
+    ///
+    /// Pads of a single row header are staggered if the distance is not zero.
+    pub fn row_span(mut self, row_span: f64) -> Self {
+        self.row_span = row_span;
+        self
+    }
+
+    /// Builds a `Header` with a shrouded body outline.
+    ///
+    /// The shroud has walls of the specified thickness and a key slot in the left wall.
+    pub fn shroud(mut self, wall: f64, key_width: f64) -> Self {
+        self.shroud = Some((wall, key_width));
+        self
+    }
+}
@@ -15,8 +15,9 @@ pub fn calc(pads: &mut [Pad], config: &Config) {
         for j in (i + 1)..=last {
             let p1 = &pads[i];
             let p2 = &pads[j];
-            let hspace = (p2.origin.x - p1.origin.x).abs() - (p1.size.x + p2.size.x) / 2.0;
-            let vspace = (p2.origin.y - p1.origin.y).abs() - (p1.size.y + p2.size.y) / 2.0;
+            let (s1, s2) = (p1.extent(), p2.extent());
+            let hspace = (p2.origin.x - p1.origin.x).abs() - (s1.x + s2.x) / 2.0;
+            let vspace = (p2.origin.y - p1.origin.y).abs() - (s1.y + s2.y) / 2.0;
             let space = hspace.max(vspace);
 
             // If pads are too near one to another, we need to shrink the mask. Minimum mask is zero (= copper).
@@ -1,15 +1,49 @@
+mod assembly;
+mod axial;
+mod cae;
 mod calc;
+mod courtyard;
+mod custom;
+mod cylindrical;
+mod dual_inline;
+mod dual_row;
+mod flange;
+mod grid_array;
+mod header;
 mod mask;
+mod module;
+mod paste;
+mod power_tab;
+mod quad_row;
+mod radial;
 mod silkscreen;
+mod single_pad;
 mod two_pin;
+mod vias;
 
 use crate::config::Config;
-use crate::drawing::{Attribute, Drawing, Layer, Size};
+use crate::drawing::{Attribute, Drawing, Element, Layer, Pad, PadShape, Size};
+use crate::error::*;
+use crate::pinout::Pinout;
 
+pub use axial::Axial;
+pub use cae::Cae;
 pub use calc::Ipc7351B;
+pub use custom::Custom;
+pub use cylindrical::Cylindrical;
+pub use dual_inline::DualInline;
+pub use dual_row::DualRow;
+pub use flange::Flange;
+pub use grid_array::GridArray;
+pub use header::Header;
+pub use module::Module;
+pub use power_tab::PowerTab;
+pub use quad_row::QuadRow;
+pub use radial::Radial;
+pub use single_pad::SinglePad;
 pub use two_pin::TwoPin;
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct PadProperties {
     pub size: Size,
     pub distance: f64,
@@ -53,6 +87,141 @@ impl PadProperties {
         }
         self
     }
+
+    /// Shortens the pads from the inner side to keep the gap between rows at least `gap`.
+    ///
+    /// Pads are not made shorter than `min_len`. Returns `false` if the gap can not be kept.
+    pub fn trim_inner(&mut self, gap: f64, min_len: f64) -> bool {
+        let d = (gap - (self.distance - self.size.x)) / 2.0;
+        if d <= 0.0 {
+            return true;
+        }
+        let d_max = (self.size.x - min_len).max(0.0);
+        self.size.x -= d.min(d_max);
+        self.distance += d.min(d_max);
+        d <= d_max
+    }
+}
+
+/// Applies settings common for all patterns to the drawn pattern.
+///
+/// Thermal vias are added to pads with split paste, i.e. exposed pads and tabs.
+pub fn finish(mut drawing: Drawing, comp_cfg: &Config, lib_cfg: &Config) -> Drawing {
+    let mut elements = Vec::with_capacity(drawing.elements.len());
+    for e in drawing.elements {
+        match e {
+            Element::Pad(mut pad) => {
+                let vias = if pad.is_smd() && !pad.paste_windows.is_empty() {
+                    vias::add(&mut pad, comp_cfg, lib_cfg)
+                } else {
+                    Vec::new()
+                };
+                elements.push(Element::Pad(pad.round_corners(lib_cfg)));
+                elements.extend(vias.into_iter().map(Element::Pad));
+            }
+            e => elements.push(e),
+        }
+    }
+    drawing.elements = elements;
+    drawing
+}
+
+/// Calculates hole and pad diameters for a through-hole lead of the specified diameter.
+///
+/// Hole diameter is the lead diameter plus `pattern.clearance.lead-to-hole` but not less
+/// than `pattern.minimum.hole-diameter`. Pad diameter is given by `ring`.
+pub fn drill(lead_diameter: f64, lib_cfg: &Config) -> (f64, f64) {
+    let lead_to_hole = lib_cfg.get_f64("pattern.clearance.lead-to-hole").unwrap();
+    let min_hole = lib_cfg.get_f64("pattern.minimum.hole-diameter").unwrap();
+
+    // Drill sizes go with 0.05 mm step
+    let hole = ((lead_diameter + lead_to_hole).max(min_hole) / 0.05 - 1e-6).ceil() * 0.05;
+    (hole, hole + 2.0 * ring(hole, lib_cfg))
+}
+
+/// Calculates the annular ring width for a hole of the specified diameter.
+///
+/// Pad diameter is the hole diameter multiplied by `pattern.ratio.pad-to-hole` but not less
+/// than required by `pattern.minimum.ring-width`.
+pub fn ring(hole: f64, lib_cfg: &Config) -> f64 {
+    let pad_to_hole = lib_cfg.get_f64("pattern.ratio.pad-to-hole").unwrap();
+    let min_ring = lib_cfg.get_f64("pattern.minimum.ring-width").unwrap();
+
+    let pad = (hole * pad_to_hole).max(hole + 2.0 * min_ring);
+    let pad = (pad / 0.01 - 1e-6).ceil() * 0.01;
+    (pad - hole) / 2.0
+}
+
+/// Creates an exposed (thermal) pad if the package has `tab-size` specified.
+pub fn exposed_pad(comp_cfg: &Config, lib_cfg: &Config, lead_count: usize) -> Result<Option<Pad>> {
+    let tab_size = match comp_cfg.get_pair("package.tab-size") {
+        Ok(tab_size) => tab_size,
+        Err(_) => return Ok(None),
+    };
+    let tab_size = comp_cfg.get_pair("pattern.tab-size").unwrap_or(tab_size);
+    let leads: Vec<String> = (1..=lead_count).map(|i| i.to_string()).collect();
+    Ok(Some(thermal_pad(
+        comp_cfg,
+        lib_cfg,
+        Size::new(tab_size.0, tab_size.1),
+        &leads,
+    )?))
+}
+
+/// Creates a thermal pad (exposed pad or tab) with split paste.
+///
+/// The pad number is taken from the pinout: it is the first pin number which does not
+/// belong to leads (e.g. `EP`). If there is no such number, the next after the last lead is used.
+pub fn thermal_pad(
+    comp_cfg: &Config,
+    lib_cfg: &Config,
+    size: Size,
+    leads: &[String],
+) -> Result<Pad> {
+    let pinout = Pinout::from_config(comp_cfg)?;
+    let name = pinout
+        .pins
+        .iter()
+        .map(|pin| &pin.number)
+        .find(|number| !leads.contains(number))
+        .cloned()
+        .unwrap_or_else(|| {
+            let last = leads.iter().filter_map(|n| n.parse::<usize>().ok()).max();
+            (last.unwrap_or_default() + 1).to_string()
+        });
+
+    let windows = paste::windows(&size, comp_cfg, lib_cfg);
+    Ok(Pad::new(&name)
+        .shape(PadShape::Rect)
+        .size(size.x, size.y)
+        .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP)
+        .paste_windows(windows))
+}
+
+// Narrow a through-hole pad along the lead row to keep the clearance between adjacent pads,
+// round pads become oblong then
+fn fit_pitch(pad: Pad, pitch: f64, horizontal: bool, lib_cfg: &Config) -> Pad {
+    let clearance = lib_cfg.get_f64("pattern.clearance.pad-to-pad").unwrap();
+    let min_ring = lib_cfg.get_f64("pattern.minimum.ring-width").unwrap();
+    let (along, across) = if horizontal {
+        (pad.size.x, pad.size.y)
+    } else {
+        (pad.size.y, pad.size.x)
+    };
+    if along <= pitch - clearance {
+        return pad;
+    }
+    let hole = pad.hole.as_ref().map(|h| h.x.min(h.y)).unwrap_or_default();
+    let along = (pitch - clearance).max(hole + 2.0 * min_ring);
+    let pad = if horizontal {
+        pad.size(along, across)
+    } else {
+        pad.size(across, along)
+    };
+    match pad.shape {
+        PadShape::Circle => pad.shape(PadShape::Oval),
+        _ => pad,
+    }
 }
 
 fn add_attributes(drawing: &mut Drawing, lib_cfg: &Config) {
@@ -68,3 +237,27 @@ fn add_attributes(drawing: &mut Drawing, lib_cfg: &Config) {
     drawing.add_attribute(ref_des);
     drawing.add_attribute(value);
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn drill() {
+        let lib_cfg = load_config!("../qeda.yml");
+        let drill = |lead_diameter| {
+            let (hole, pad) = super::drill(lead_diameter, &lib_cfg);
+            // Round off to avoid floating point noise
+            (
+                (hole * 1000.0).round() / 1000.0,
+                (pad * 1000.0).round() / 1000.0,
+            )
+        };
+
+        // Hole is rounded up to 0.05 mm, the ring is 0.2 mm at least
+        assert_eq!(drill(0.6), (0.7, 1.1));
+        assert_eq!(drill(0.63), (0.75, 1.15));
+        // Pad-to-hole ratio wins for large holes
+        assert_eq!(drill(1.0), (1.1, 1.65));
+        // Minimum hole diameter
+        assert_eq!(drill(0.05), (0.2, 0.6));
+    }
+}
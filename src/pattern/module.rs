@@ -0,0 +1,203 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+use super::{assembly, courtyard, mask, silkscreen, PadProperties};
+
+// Length of the board edge hint beyond the body at each side
+const EDGE_HINT_OVERHANG: f64 = 1.0;
+
+/// Pattern of a module with castellated or LGA pads along its sides.
+#[derive(Debug, Default)]
+pub struct Module {
+    pad_props_x: PadProperties,
+    pad_props_y: PadProperties,
+    pitch: f64,
+    lead_counts: [usize; 4],
+    body: Rect,
+    ground_pads: Vec<Pad>,
+    antenna: Option<f64>,
+    row_offset: Option<f64>,
+    polarity_mark: Option<String>,
+}
+
+impl Module {
+    /// Builds a `Module` with an antenna area at the top side.
+    ///
+    /// The area spans the body width and has the specified depth. Copper is not allowed in
+    /// the area, and the top edge of the body should be aligned to the board edge.
+    pub fn antenna(mut self, depth: f64) -> Self {
+        self.antenna = Some(depth);
+        self
+    }
+
+    /// Builds a `Module` with modified body.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self
+    }
+
+    /// Draws module pattern.
+    ///
+    /// Pin 1 is the top one in the left row. Numbering goes counter-clockwise: downwards
+    /// along the left row, rightwards along the bottom one, upwards along the right one and
+    /// leftwards along the top one.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let [left, bottom, right, top] = self.lead_counts;
+        let dx = self.pad_props_x.distance / 2.0;
+        let dy = self.pad_props_y.distance / 2.0;
+        let pad_x = Pad::new("")
+            .shape(PadShape::Rect)
+            .size(self.pad_props_x.size.x, self.pad_props_x.size.y)
+            .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP);
+        let pad_y = pad_x
+            .clone()
+            .size(self.pad_props_y.size.y, self.pad_props_y.size.x);
+
+        let antenna = self.antenna.map(|depth| {
+            let body = &self.body;
+            Rect::new(body.p.0.x, body.p.0.y, body.p.1.x, body.p.0.y + depth)
+        });
+
+        // Offset of the first lead in a centered row, side rows are centered below the antenna
+        // unless the offset is specified
+        let start = |count: usize| -self.pitch * (count as f64 - 1.0) / 2.0;
+        let cy = self
+            .row_offset
+            .unwrap_or_else(|| self.antenna.unwrap_or_default() / 2.0);
+        let mut pads = Vec::with_capacity(left + bottom + right + top);
+        for i in 0..left {
+            pads.push(
+                pad_x
+                    .clone()
+                    .origin(-dx, cy + start(left) + self.pitch * i as f64),
+            );
+        }
+        for i in 0..bottom {
+            pads.push(
+                pad_y
+                    .clone()
+                    .origin(start(bottom) + self.pitch * i as f64, dy),
+            );
+        }
+        for i in 0..right {
+            pads.push(
+                pad_x
+                    .clone()
+                    .origin(dx, cy - start(right) - self.pitch * i as f64),
+            );
+        }
+        for i in 0..top {
+            pads.push(
+                pad_y
+                    .clone()
+                    .origin(-start(top) - self.pitch * i as f64, -dy),
+            );
+        }
+        for (i, pad) in pads.iter_mut().enumerate() {
+            pad.name = (i + 1).to_string();
+        }
+        pads.extend(self.ground_pads.iter().cloned());
+
+        mask::calc(&mut pads, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let lines = self
+            .body
+            .clone()
+            .expand(line_width / 2.0)
+            .line_width(line_width)
+            .layer(Layer::SILKSCREEN_TOP)
+            .to_lines();
+        silkscreen::draw_clipped(drawing, lines, &pads, lib_cfg);
+
+        let pin1 = pads.iter().find(|p| p.name == "1");
+        if let (Some(mark), Some(pin1)) = (&self.polarity_mark, pin1) {
+            silkscreen::draw_pin1_mark(drawing, mark, pin1, &pads, lib_cfg);
+        }
+        assembly::draw_body(drawing, &self.body, pin1, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+
+        if let Some(antenna) = &antenna {
+            drawing.add_keep_out(
+                KeepOut::new(antenna.p.0.x, antenna.p.0.y, antenna.p.1.x, antenna.p.1.y)
+                    .layer(Layer::COPPER_TOP | Layer::COPPER_BOTTOM),
+            );
+            drawing.add_line(
+                Line::new(antenna.p.0.x, antenna.p.1.y, antenna.p.1.x, antenna.p.1.y)
+                    .width(line_width)
+                    .layer(Layer::ASSEMBLY_TOP),
+            );
+
+            // Board edge hint. Only the antenna side has to be placed at the board edge,
+            // castellated modules may be placed anywhere on the board so they get no hint
+            let y = self.body.p.0.y;
+            drawing.add_line(
+                Line::new(
+                    self.body.p.0.x - EDGE_HINT_OVERHANG,
+                    y,
+                    self.body.p.1.x + EDGE_HINT_OVERHANG,
+                    y,
+                )
+                .width(line_width)
+                .layer(Layer::ASSEMBLY_TOP),
+            );
+        }
+
+        let excess = self.pad_props_x.courtyard.max(self.pad_props_y.courtyard);
+        courtyard::draw(drawing, &pads, Some(&self.body), excess, lib_cfg);
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `Module` with inner ground pads.
+    pub fn ground_pads(mut self, pads: Vec<Pad>) -> Self {
+        self.ground_pads = pads;
+        self
+    }
+
+    /// Builds a `Module` with modified lead counts on the left, bottom, right and top sides.
+    pub fn lead_counts(mut self, lead_counts: [usize; 4]) -> Self {
+        self.lead_counts = lead_counts;
+        self
+    }
+
+    /// Builds a `Module` with modified pad properties for the left/right rows (`pad_props_x`)
+    /// and for the top/bottom rows (`pad_props_y`).
+    ///
+    /// Both are calculated as for horizontal pads, i.e. `size.x` is along the lead.
+    pub fn pad_properties(
+        mut self,
+        pad_props_x: PadProperties,
+        pad_props_y: PadProperties,
+    ) -> Self {
+        self.pad_props_x = pad_props_x;
+        self.pad_props_y = pad_props_y;
+        self
+    }
+
+    /// Builds a `Module` with modified pitch.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `Module` with the left and right rows shifted downwards by the specified offset.
+    ///
+    /// By default the rows are centered below the antenna area.
+    pub fn row_offset(mut self, offset: f64) -> Self {
+        self.row_offset = Some(offset);
+        self
+    }
+
+    /// Builds a `Module` with a polarity mark near pin 1.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+}
@@ -0,0 +1,105 @@
+use crate::config::Config;
+use crate::drawing::{Rect, Size};
+
+/// Splits a pad paste area into a grid of windows.
+///
+/// Grid dimensions are taken from `pattern.paste-grid` of the component or calculated
+/// from the maximum window size. Windows cover the specified part of the pad area.
+pub fn windows(size: &Size, comp_cfg: &Config, lib_cfg: &Config) -> Vec<Rect> {
+    let coverage = comp_cfg
+        .get_f64("pattern.paste-coverage")
+        .unwrap_or_else(|_| lib_cfg.get_f64("pattern.paste.coverage").unwrap());
+    let window_size = lib_cfg.get_f64("pattern.paste.window-size").unwrap();
+
+    let (nx, ny) = if let Ok(grid) = comp_cfg.get_pair("pattern.paste-grid") {
+        (grid.0.round().max(1.0), grid.1.round().max(1.0))
+    } else {
+        (
+            (size.x * coverage.sqrt() / window_size).ceil().max(1.0),
+            (size.y * coverage.sqrt() / window_size).ceil().max(1.0),
+        )
+    };
+
+    let cell_x = size.x / nx;
+    let cell_y = size.y / ny;
+    let window_x = cell_x * coverage.sqrt();
+    let window_y = cell_y * coverage.sqrt();
+
+    let mut result = Vec::new();
+    for j in 0..ny as usize {
+        for i in 0..nx as usize {
+            let x = -size.x / 2.0 + cell_x * (i as f64 + 0.5);
+            let y = -size.y / 2.0 + cell_y * (j as f64 + 0.5);
+            result.push(Rect::new(
+                x - window_x / 2.0,
+                y - window_y / 2.0,
+                x + window_x / 2.0,
+                y + window_y / 2.0,
+            ));
+        }
+    }
+    result
+}
+
+/// Places paste windows between the vias of a pad.
+///
+/// The pad is split into cells by the rows and columns of vias. A window in each cell covers
+/// the specified part of the cell area but keeps `keep` distance from the via centers.
+/// Windows narrower than `keep` are dropped.
+pub fn windows_between(
+    size: &Size,
+    xs: &[f64],
+    ys: &[f64],
+    keep: f64,
+    comp_cfg: &Config,
+    lib_cfg: &Config,
+) -> Vec<Rect> {
+    let coverage = comp_cfg
+        .get_f64("pattern.paste-coverage")
+        .unwrap_or_else(|_| lib_cfg.get_f64("pattern.paste.coverage").unwrap());
+    let k = coverage.sqrt();
+
+    let mut result = Vec::new();
+    for (y0, y1) in spans(size.y, ys, keep, k) {
+        for (x0, x1) in spans(size.x, xs, keep, k) {
+            result.push(Rect::new(x0, y0, x1, y1));
+        }
+    }
+    result
+}
+
+// Get window spans between via lines along a pad side
+fn spans(side: f64, lines: &[f64], keep: f64, k: f64) -> Vec<(f64, f64)> {
+    let mut edges = vec![(-side / 2.0, 0.0)];
+    edges.extend(lines.iter().map(|&l| (l, keep)));
+    edges.push((side / 2.0, 0.0));
+    edges
+        .windows(2)
+        .filter_map(|e| {
+            let (a, b) = (e[0].0 + e[0].1, e[1].0 - e[1].1);
+            let width = (b - a).min((e[1].0 - e[0].0) * k);
+            if width < keep {
+                return None;
+            }
+            let c = (a + b) / 2.0;
+            Some((c - width / 2.0, c + width / 2.0))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn spans() {
+        // Two via lines at ±1 with 0.5 keep distance on a 4 mm side and full coverage
+        let spans = super::spans(4.0, &[-1.0, 1.0], 0.5, 1.0);
+        assert_eq!(spans, [(-2.0, -1.5), (-0.5, 0.5), (1.5, 2.0)]);
+
+        // Windows are shrunk to the coverage and dropped if narrower than the keep distance
+        let spans = super::spans(4.0, &[-1.0, 1.0], 0.5, 0.4);
+        assert_eq!(spans, [(-0.4, 0.4)]);
+
+        // No vias
+        assert_eq!(super::spans(2.0, &[], 0.5, 0.8), [(-0.8, 0.8)]);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+use super::{assembly, courtyard, mask, silkscreen, PadProperties};
+
+/// Pattern of a package with a row of leads on the left and a large tab on the right.
+#[derive(Debug, Default)]
+pub struct PowerTab {
+    lead_props: PadProperties,
+    tab_props: PadProperties,
+    pitch: f64,
+    leads: Vec<Option<String>>,
+    tab: Pad,
+    body: Rect,
+    polarity_mark: Option<String>,
+}
+
+impl PowerTab {
+    /// Builds a `PowerTab` with modified body.
+    ///
+    /// Body is shifted along the x-axis by `offset` towards the tab.
+    pub fn body(mut self, width: f64, height: f64, offset: f64) -> Self {
+        self.body.p.0.x = offset - width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = offset + width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self
+    }
+
+    /// Draws power tab pattern.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let x = -self.lead_props.distance / 2.0;
+        let pad = Pad::new("")
+            .shape(PadShape::Rect)
+            .size(self.lead_props.size.x, self.lead_props.size.y)
+            .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP);
+
+        let mut pads = Vec::with_capacity(self.leads.len() + 1);
+        let y0 = -self.pitch * (self.leads.len() as f64 - 1.0) / 2.0;
+        for (i, name) in self.leads.iter().enumerate() {
+            if let Some(name) = name {
+                let y = y0 + self.pitch * i as f64;
+                pads.push(pad.clone().name(name).origin(x, y));
+            }
+        }
+
+        let tab = self
+            .tab
+            .clone()
+            .size(self.tab_props.size.x, self.tab_props.size.y)
+            .origin(self.tab_props.distance / 2.0, 0.0);
+        pads.push(tab);
+
+        mask::calc(&mut pads, lib_cfg);
+
+        silkscreen::draw_body(drawing, &self.body, &pads, lib_cfg);
+
+        let pin1 = pads.iter().find(|p| p.name == "1");
+        if let (Some(mark), Some(pin1)) = (&self.polarity_mark, pin1) {
+            silkscreen::draw_pin1_mark(drawing, mark, pin1, &pads, lib_cfg);
+        }
+        assembly::draw_body(drawing, &self.body, pin1, lib_cfg);
+
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&self.body),
+            self.lead_props.courtyard,
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `PowerTab` with an arbitrary lead layout.
+    ///
+    /// Leads are listed from top to bottom, `None` stands for a missing lead.
+    pub fn leads(mut self, leads: Vec<Option<String>>) -> Self {
+        self.leads = leads;
+        self
+    }
+
+    /// Builds a `PowerTab` with modified lead and tab pad properties.
+    pub fn pad_properties(mut self, lead_props: PadProperties, tab_props: PadProperties) -> Self {
+        self.lead_props = lead_props;
+        self.tab_props = tab_props;
+        self
+    }
+
+    /// Builds a `PowerTab` with modified pitch.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `PowerTab` with a polarity mark near pin 1.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+
+    /// Builds a `PowerTab` with modified tab pad (its size and position are set by `draw`).
+    pub fn tab(mut self, tab: Pad) -> Self {
+        self.tab = tab;
+        self
+    }
+}
@@ -0,0 +1,294 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+use super::{assembly, courtyard, mask, silkscreen, PadProperties};
+
+#[derive(Debug, Default)]
+pub struct QuadRow {
+    pad_props_x: PadProperties,
+    pad_props_y: PadProperties,
+    pitch: f64,
+    lead_count_x: usize,
+    lead_count_y: usize,
+    body: Rect,
+    exposed_pad: Option<Pad>,
+    first_lead: usize,
+    polarity_mark: Option<String>,
+}
+
+impl QuadRow {
+    /// Builds a `QuadRow` with modified body.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self
+    }
+
+    /// Draws quad row pattern.
+    ///
+    /// By default pin 1 is the top one in the left row. Numbering goes counter-clockwise:
+    /// downwards along the left row, rightwards along the bottom one, upwards along the right
+    /// one and leftwards along the top one.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let clearance = lib_cfg.get_f64("pattern.clearance.pad-to-pad").unwrap();
+        let min_len = lib_cfg.get_f64("pattern.minimum.pad-length").unwrap();
+        let (pad_props_x, pad_props_y) = self.trim_corners(clearance, min_len);
+
+        let nx = self.lead_count_x;
+        let ny = self.lead_count_y;
+        let x0 = -self.pitch * (nx as f64 - 1.0) / 2.0;
+        let y0 = -self.pitch * (ny as f64 - 1.0) / 2.0;
+        let dx = pad_props_x.distance / 2.0;
+        let dy = pad_props_y.distance / 2.0;
+        let pad_x = Pad::new("")
+            .shape(PadShape::Rect)
+            .size(pad_props_x.size.x, pad_props_x.size.y)
+            .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP);
+        let pad_y = pad_x.clone().size(pad_props_y.size.y, pad_props_y.size.x);
+
+        let mut pads = Vec::with_capacity(2 * (nx + ny));
+        let mut add_pad = |pad: &Pad, x: f64, y: f64| {
+            pads.push(pad.clone().origin(x, y));
+        };
+        for i in 0..ny {
+            add_pad(&pad_x, -dx, y0 + self.pitch * i as f64);
+        }
+        for i in 0..nx {
+            add_pad(&pad_y, x0 + self.pitch * i as f64, dy);
+        }
+        for i in 0..ny {
+            add_pad(&pad_x, dx, -y0 - self.pitch * i as f64);
+        }
+        for i in 0..nx {
+            add_pad(&pad_y, -x0 - self.pitch * i as f64, -dy);
+        }
+        let count = pads.len();
+        if count > 0 {
+            pads.rotate_left(self.first_lead % count);
+        }
+        for (i, pad) in pads.iter_mut().enumerate() {
+            pad.name = (i + 1).to_string();
+        }
+
+        if let Some(exposed_pad) = &self.exposed_pad {
+            pads.push(exposed_pad.clone());
+        }
+
+        mask::calc(&mut pads, lib_cfg);
+        silkscreen::draw_body(drawing, &self.body, &pads, lib_cfg);
+
+        let excess = self.pad_props_x.courtyard.max(self.pad_props_y.courtyard);
+        let pin1 = pads.iter().find(|p| p.name == "1");
+        if let (Some(mark), Some(pin1)) = (&self.polarity_mark, pin1) {
+            silkscreen::draw_pin1_mark(drawing, mark, pin1, &pads, lib_cfg);
+        }
+        assembly::draw_body(drawing, &self.body, pin1, lib_cfg);
+
+        courtyard::draw(drawing, &pads, Some(&self.body), excess, lib_cfg);
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `QuadRow` with an exposed pad in the center.
+    pub fn exposed_pad(mut self, pad: Pad) -> Self {
+        self.exposed_pad = Some(pad);
+        self
+    }
+
+    /// Builds a `QuadRow` with pin 1 at the specified position.
+    ///
+    /// Position is an index in the default counter-clockwise order starting from zero.
+    pub fn first_lead(mut self, index: usize) -> Self {
+        self.first_lead = index;
+        self
+    }
+
+    /// Builds a `QuadRow` with modified lead counts along X (top and bottom rows)
+    /// and along Y (left and right rows).
+    pub fn lead_counts(mut self, lead_count_x: usize, lead_count_y: usize) -> Self {
+        self.lead_count_x = lead_count_x;
+        self.lead_count_y = lead_count_y;
+        self
+    }
+
+    /// Builds a `QuadRow` with modified pad properties for the left/right rows (`pad_props_x`)
+    /// and for the top/bottom rows (`pad_props_y`).
+    ///
+    /// Both are calculated as for horizontal pads, i.e. `size.x` is along the lead.
+    pub fn pad_properties(
+        mut self,
+        pad_props_x: PadProperties,
+        pad_props_y: PadProperties,
+    ) -> Self {
+        self.pad_props_x = pad_props_x;
+        self.pad_props_y = pad_props_y;
+        self
+    }
+
+    /// Builds a `QuadRow` with modified pitch.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `QuadRow` with a polarity mark near pin 1.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+
+    // Shorten pads from the inner side if they are too near to the exposed pad or one to another
+    // at the corners, only the row needing less trimming is shortened at the corners
+    fn trim_corners(&self, clearance: f64, min_len: f64) -> (PadProperties, PadProperties) {
+        let mut pad_props_x = self.pad_props_x.clone();
+        let mut pad_props_y = self.pad_props_y.clone();
+        let mut ok = true;
+        if let Some(exposed_pad) = &self.exposed_pad {
+            ok &= pad_props_x.trim_inner(exposed_pad.size.x + 2.0 * clearance, min_len);
+            ok &= pad_props_y.trim_inner(exposed_pad.size.y + 2.0 * clearance, min_len);
+        }
+        if self.lead_count_x > 0 && self.lead_count_y > 0 {
+            let (space_x, space_y) = self.corner_space(&pad_props_x, &pad_props_y);
+            let (dx, dy) = (clearance - space_x, clearance - space_y);
+            if dx > 0.0 && dy > 0.0 {
+                let gap_x = pad_props_x.distance - pad_props_x.size.x + 2.0 * dx;
+                let gap_y = pad_props_y.distance - pad_props_y.size.x + 2.0 * dy;
+                // The other row is trimmed only if the first one reaches the minimum length
+                let (first, second) = if dx <= dy {
+                    ((&mut pad_props_x, gap_x), (&mut pad_props_y, gap_y))
+                } else {
+                    ((&mut pad_props_y, gap_y), (&mut pad_props_x, gap_x))
+                };
+                if !first.0.trim_inner(first.1, min_len) {
+                    second.0.trim_inner(second.1, min_len);
+                }
+                let (space_x, space_y) = self.corner_space(&pad_props_x, &pad_props_y);
+                ok &= space_x.max(space_y) >= clearance - 1e-6;
+            }
+        }
+        if !ok {
+            warn!("pads are shortened to the minimum length, the clearance is violated");
+        }
+        (pad_props_x, pad_props_y)
+    }
+
+    // Get spaces along X and Y between the extents of adjacent corner pads: the last one of
+    // the left row and the first one of the bottom row, other corners are symmetric
+    fn corner_space(&self, pad_props_x: &PadProperties, pad_props_y: &PadProperties) -> (f64, f64) {
+        let corner_x = self.pitch * (self.lead_count_x as f64 - 1.0) / 2.0;
+        let corner_y = self.pitch * (self.lead_count_y as f64 - 1.0) / 2.0;
+        let left = Pad::new("")
+            .size(pad_props_x.size.x, pad_props_x.size.y)
+            .origin(-pad_props_x.distance / 2.0, corner_y);
+        let bottom = Pad::new("")
+            .size(pad_props_y.size.y, pad_props_y.size.x)
+            .origin(-corner_x, pad_props_y.distance / 2.0);
+        let (a, b) = (left.extent(), bottom.extent());
+        (
+            (bottom.origin.x - left.origin.x) - (a.x + b.x) / 2.0,
+            (bottom.origin.y - left.origin.y) - (a.y + b.y) / 2.0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Get pads of a drawn pattern
+    fn pads(quad_row: &QuadRow) -> Vec<Pad> {
+        let mut drawing = Drawing::new();
+        quad_row.draw(&mut drawing, &load_config!("../qeda.yml"));
+        drawing
+            .elements
+            .into_iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn corners() {
+        let pad_props = PadProperties {
+            size: Size::new(1.5, 0.3),
+            distance: 2.6,
+            ..Default::default()
+        };
+        let quad_row = QuadRow::default()
+            .pad_properties(pad_props.clone(), pad_props)
+            .pitch(0.5)
+            .lead_counts(3, 3);
+        let pads = pads(&quad_row);
+        let pad = |name: &str| pads.iter().find(|p| p.name == name).unwrap();
+
+        // Counter-clockwise from the top left one
+        let (p1, p3, p4, p7, p10) = (pad("1"), pad("3"), pad("4"), pad("7"), pad("10"));
+        assert!(p1.origin.x < 0.0 && p1.origin.y == -0.5);
+        assert!(p3.origin.x == p1.origin.x && p3.origin.y == 0.5);
+        assert!(p4.origin.x == -0.5 && p4.origin.y > 0.0);
+        assert!(p7.origin.x > 0.0 && p7.origin.y == 0.5);
+        assert!(p10.origin.x == 0.5 && p10.origin.y < 0.0);
+
+        // Corner pads keep the clearance, only the left and right rows are trimmed
+        let space_x = (p4.origin.x - p3.origin.x) - (p3.size.x + p4.size.x) / 2.0;
+        let space_y = (p4.origin.y - p3.origin.y) - (p3.size.y + p4.size.y) / 2.0;
+        assert!(space_x.max(space_y) >= 0.2 - 1e-9);
+        assert!((p1.size.x - 1.2).abs() < 1e-9);
+        assert_eq!(p4.size.y, 1.5);
+
+        // QFP-32 without an exposed pad, corner pads overlap before trimming
+        let pad_props = PadProperties {
+            size: Size::new(1.6, 0.3),
+            distance: 4.6,
+            ..Default::default()
+        };
+        let quad_row = QuadRow::default()
+            .pad_properties(pad_props.clone(), pad_props)
+            .pitch(0.5)
+            .lead_counts(8, 8);
+        let pads = self::pads(&quad_row);
+        let pad = |name: &str| pads.iter().find(|p| p.name == name).unwrap();
+        for (a, b) in [("8", "9"), ("16", "17"), ("24", "25"), ("32", "1")] {
+            let (a, b) = (pad(a), pad(b));
+            let (ea, eb) = (a.extent(), b.extent());
+            let space_x = (a.origin.x - b.origin.x).abs() - (ea.x + eb.x) / 2.0;
+            let space_y = (a.origin.y - b.origin.y).abs() - (ea.y + eb.y) / 2.0;
+            assert!(space_x.max(space_y) >= 0.2 - 1e-9);
+        }
+        assert!((pad("1").size.x - 1.0).abs() < 1e-9);
+        assert_eq!(pad("9").size.y, 1.6);
+    }
+
+    #[test]
+    fn first_lead() {
+        // PLCC-20 has pin 1 in the middle of the top row
+        let pad_props = PadProperties {
+            size: Size::new(2.0, 0.6),
+            distance: 8.0,
+            ..Default::default()
+        };
+        let quad_row = QuadRow::default()
+            .pad_properties(pad_props.clone(), pad_props)
+            .pitch(1.27)
+            .lead_counts(5, 5)
+            .first_lead(2 * 5 + 5 + 5 / 2);
+        let pads = pads(&quad_row);
+        let origin = |name: &str| {
+            let pad = pads.iter().find(|p| p.name == name).unwrap();
+            (pad.origin.x, pad.origin.y)
+        };
+
+        // Counter-clockwise, i.e. to the left along the top row
+        assert_eq!(origin("1"), (0.0, -4.0));
+        assert_eq!(origin("2"), (-1.27, -4.0));
+        assert_eq!(origin("3"), (-2.54, -4.0));
+        assert!(origin("4").0 < -4.0 && origin("4").1 == -2.54);
+        assert_eq!(origin("20"), (1.27, -4.0));
+    }
+}
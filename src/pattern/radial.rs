@@ -0,0 +1,126 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::packages::PackageType;
+
+use super::{assembly, courtyard, mask, silkscreen};
+
+/// Pattern of a through-hole part with radial leads and a round body.
+#[derive(Debug, Default)]
+pub struct Radial {
+    pad: Pad,
+    pitch: f64,
+    diameter: f64,
+    polarity_mark: Option<String>,
+}
+
+impl Radial {
+    /// Builds a `Radial` with modified body diameter.
+    pub fn body(mut self, diameter: f64) -> Self {
+        self.diameter = diameter;
+        self
+    }
+
+    /// Draws radial pattern.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let x = self.pitch / 2.0;
+        let mut pads = vec![
+            self.pad
+                .clone()
+                .name("1")
+                .shape(PadShape::Rect)
+                .origin(-x, 0.0),
+            self.pad.clone().name("2").origin(x, 0.0),
+        ];
+        mask::calc(&mut pads, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let radius = (self.diameter + line_width) / 2.0;
+        silkscreen::draw_circle(drawing, Point::new(0.0, 0.0), radius, &pads, lib_cfg);
+
+        if let Some(mark) = &self.polarity_mark {
+            silkscreen::draw_pin1_mark(drawing, mark, &pads[0], &pads, lib_cfg);
+        }
+
+        let r = self.diameter / 2.0;
+        let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+        drawing.add_circle(
+            Circle::new(0.0, 0.0, r)
+                .width(line_width)
+                .layer(Layer::ASSEMBLY_TOP),
+        );
+        if self.polarity_mark.is_some() {
+            // Pin 1 dot is inside the body edge to the left
+            let size = lib_cfg.get_f64("pattern.polarity.size").unwrap();
+            assembly::draw_dot(drawing, Point::new(size - r, 0.0), lib_cfg);
+        }
+        let d = r / 2f64.sqrt();
+        assembly::draw_ref_des(drawing, &Rect::new(-d, -d, d, d), lib_cfg);
+
+        let body = Rect::new(-r, -r, r, r);
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&body),
+            courtyard::excess(PackageType::ThroughHole, lib_cfg),
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `Radial` with modified template pad.
+    pub fn pad(mut self, pad: Pad) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    /// Builds a `Radial` with modified distance between leads.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `Radial` with a polarity mark near pin 1.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads() {
+        let pad = Pad::new("")
+            .hole(0.8, 0.8)
+            .size(1.6, 1.6)
+            .layers(Layer::COPPER_TOP | Layer::COPPER_BOTTOM);
+        let mut drawing = Drawing::new();
+        Radial::default()
+            .pad(pad)
+            .pitch(2.5)
+            .body(5.0)
+            .draw(&mut drawing, &load_config!("../qeda.yml"));
+        let pads: Vec<_> = drawing
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect();
+
+        // Square pin 1 on the left
+        assert_eq!(pads.len(), 2);
+        assert_eq!(pads[0].name, "1");
+        assert_eq!((pads[0].origin.x, pads[0].origin.y), (-1.25, 0.0));
+        assert!(matches!(pads[0].shape, PadShape::Rect));
+        assert_eq!(pads[1].name, "2");
+        assert_eq!((pads[1].origin.x, pads[1].origin.y), (1.25, 0.0));
+        assert!(matches!(pads[1].shape, PadShape::Circle));
+    }
+}
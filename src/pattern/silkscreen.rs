@@ -1,7 +1,10 @@
+use std::f64::consts::PI;
+
 use crate::config::Config;
-use crate::drawing::{Drawing, Layer, Line, Pad, Rect};
+use crate::drawing::{Circle, Drawing, Layer, Line, Pad, PadShape, Point, Rect};
 
-pub fn draw_body(drawing: &mut Drawing, body: &Rect, _pads: &[Pad], lib_cfg: &Config) {
+/// Draws a silkscreen outline around the body avoiding pads.
+pub fn draw_body(drawing: &mut Drawing, body: &Rect, pads: &[Pad], lib_cfg: &Config) {
     let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
 
     let rect = body
@@ -10,6 +13,263 @@ pub fn draw_body(drawing: &mut Drawing, body: &Rect, _pads: &[Pad], lib_cfg: &Co
         .line_width(line_width)
         .layer(Layer::SILKSCREEN_TOP);
 
-    let lines: Vec<Line> = rect.to_lines();
-    drawing.add_lines(lines);
+    draw_clipped(drawing, rect.to_lines(), pads, lib_cfg);
+}
+
+/// Draws a silkscreen circle avoiding pads.
+///
+/// If the circle crosses any pad, it is approximated with line segments which are clipped.
+pub fn draw_circle(
+    drawing: &mut Drawing,
+    center: Point,
+    radius: f64,
+    pads: &[Pad],
+    lib_cfg: &Config,
+) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+    let clearance = lib_cfg
+        .get_f64("pattern.clearance.pad-to-silkscreen")
+        .unwrap();
+    let crosses = pads.iter().any(|pad| {
+        let space = clearance + pad.mask.max(0.0) + line_width / 2.0;
+        let dx = (pad.origin.x - center.x).abs();
+        let dy = (pad.origin.y - center.y).abs();
+        let (near, far) = if let PadShape::Circle = pad.shape {
+            let distance = dx.hypot(dy);
+            let r = pad.size.x / 2.0 + space;
+            ((distance - r).max(0.0), distance + r)
+        } else {
+            let size = pad.extent();
+            let (w, h) = (size.x / 2.0 + space, size.y / 2.0 + space);
+            (
+                (dx - w).max(0.0).hypot((dy - h).max(0.0)),
+                (dx + w).hypot(dy + h),
+            )
+        };
+        // Touching is not crossing
+        near < radius - 1e-6 && radius < far - 1e-6
+    });
+    if !crosses {
+        drawing.add_circle(
+            Circle::new(center.x, center.y, radius)
+                .width(line_width)
+                .layer(Layer::SILKSCREEN_TOP),
+        );
+        return;
+    }
+
+    let count = 72;
+    let point = |i: usize| {
+        let a = 2.0 * PI * i as f64 / count as f64;
+        (center.x + radius * a.cos(), center.y + radius * a.sin())
+    };
+    let lines = (0..count)
+        .map(|i| {
+            let (x0, y0) = point(i);
+            let (x1, y1) = point(i + 1);
+            Line::new(x0, y0, x1, y1)
+                .width(line_width)
+                .layer(Layer::SILKSCREEN_TOP)
+        })
+        .collect();
+    draw_clipped(drawing, lines, pads, lib_cfg);
+}
+
+/// Draws silkscreen lines removing their parts which are too near to pads.
+///
+/// Pads are expanded by their mask and `pattern.clearance.pad-to-silkscreen`. Clipped parts
+/// shorter than `pattern.minimum.silkscreen-length` are dropped.
+pub fn draw_clipped(drawing: &mut Drawing, lines: Vec<Line>, pads: &[Pad], lib_cfg: &Config) {
+    let clearance = lib_cfg
+        .get_f64("pattern.clearance.pad-to-silkscreen")
+        .unwrap();
+    let min_len = lib_cfg
+        .get_f64("pattern.minimum.silkscreen-length")
+        .unwrap();
+    for line in lines {
+        let mut visible = vec![(0.0, 1.0)];
+        for pad in pads {
+            let space = clearance + pad.mask.max(0.0) + line.width / 2.0;
+            let size = pad.extent();
+            let rect = Rect::new(
+                pad.origin.x - size.x / 2.0,
+                pad.origin.y - size.y / 2.0,
+                pad.origin.x + size.x / 2.0,
+                pad.origin.y + size.y / 2.0,
+            )
+            .expand(space);
+            if let Some((t0, t1)) = intersection(&line, &rect) {
+                visible = visible
+                    .into_iter()
+                    .flat_map(|(s, e)| {
+                        let mut parts = Vec::new();
+                        if t1 <= s || t0 >= e {
+                            parts.push((s, e));
+                        } else {
+                            if t0 > s {
+                                parts.push((s, t0));
+                            }
+                            if t1 < e {
+                                parts.push((t1, e));
+                            }
+                        }
+                        parts
+                    })
+                    .collect();
+            }
+        }
+        let len = (line.p.1.x - line.p.0.x).hypot(line.p.1.y - line.p.0.y);
+        let clipped = visible != [(0.0, 1.0)];
+        for (s, e) in visible {
+            if clipped && (e - s) * len < min_len {
+                continue;
+            }
+            let p0 = point_at(&line, s);
+            let p1 = point_at(&line, e);
+            drawing.add_line(
+                Line::new(p0.x, p0.y, p1.x, p1.y)
+                    .width(line.width)
+                    .layer(line.layer),
+            );
+        }
+    }
+}
+
+// Find a part of the line inside the rectangle (Liang-Barsky algorithm).
+// Returns parameters of the part boundaries: 0 is the line start, 1 is the line end.
+fn intersection(line: &Line, rect: &Rect) -> Option<(f64, f64)> {
+    let (x0, y0) = (line.p.0.x, line.p.0.y);
+    let dx = line.p.1.x - x0;
+    let dy = line.p.1.y - y0;
+    let mut t0 = 0.0f64;
+    let mut t1 = 1.0f64;
+    for (p, q) in &[
+        (-dx, x0 - rect.p.0.x),
+        (dx, rect.p.1.x - x0),
+        (-dy, y0 - rect.p.0.y),
+        (dy, rect.p.1.y - y0),
+    ] {
+        if p.abs() < f64::EPSILON {
+            if *q < 0.0 {
+                return None;
+            }
+        } else if *p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+    }
+    if t0 < t1 {
+        Some((t0, t1))
+    } else {
+        None
+    }
+}
+
+// Get a point of the line by its parameter
+fn point_at(line: &Line, t: f64) -> Point {
+    Point::new(
+        line.p.0.x + (line.p.1.x - line.p.0.x) * t,
+        line.p.0.y + (line.p.1.y - line.p.0.y) * t,
+    )
+}
+
+/// Draws a polarity mark centered at the specified point.
+///
+/// Mark styles are `.` (dot), `-`, `+`, `x`, `o` (circle) and `bar` (vertical line). Mark size
+/// is taken from `pattern.polarity.size`.
+pub fn draw_polarity_mark(drawing: &mut Drawing, mark: &str, center: Point, lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+    let size = lib_cfg.get_f64("pattern.polarity.size").unwrap();
+    let d = (size - line_width) / 2.0;
+    let (x, y) = (center.x, center.y);
+    let lines = match mark {
+        "-" => vec![Line::new(x - d, y, x + d, y)],
+        "bar" => vec![Line::new(x, y - d, x, y + d)],
+        "+" => vec![Line::new(x - d, y, x + d, y), Line::new(x, y - d, x, y + d)],
+        "x" => {
+            let d = d / 2f64.sqrt();
+            vec![
+                Line::new(x - d, y - d, x + d, y + d),
+                Line::new(x - d, y + d, x + d, y - d),
+            ]
+        }
+        "o" => {
+            drawing.add_circle(
+                Circle::new(x, y, d)
+                    .width(line_width)
+                    .layer(Layer::SILKSCREEN_TOP),
+            );
+            Vec::new()
+        }
+        "." => {
+            // Filled dot
+            drawing.add_circle(
+                Circle::new(x, y, size / 4.0)
+                    .width(size / 2.0)
+                    .layer(Layer::SILKSCREEN_TOP),
+            );
+            Vec::new()
+        }
+        _ => {
+            warn!("unknown polarity mark '{}' is not drawn", mark);
+            Vec::new()
+        }
+    };
+    for line in lines {
+        drawing.add_line(line.width(line_width).layer(Layer::SILKSCREEN_TOP));
+    }
+}
+
+/// Draws a pin 1 mark outside the pad with clearance.
+///
+/// The mark is placed diagonally away from the footprint origin. If it collides with another
+/// pad there, it is moved beside the pad horizontally or vertically. Mark `none` is not drawn.
+pub fn draw_pin1_mark(
+    drawing: &mut Drawing,
+    mark: &str,
+    pin1: &Pad,
+    pads: &[Pad],
+    lib_cfg: &Config,
+) {
+    if mark == "none" {
+        return;
+    }
+    let clearance = lib_cfg
+        .get_f64("pattern.clearance.pad-to-silkscreen")
+        .unwrap();
+    let size = lib_cfg.get_f64("pattern.polarity.size").unwrap();
+    let extent = pin1.extent();
+    let space = clearance + pin1.mask + size / 2.0;
+    let sx = if pin1.origin.x > f64::EPSILON {
+        1.0
+    } else {
+        -1.0
+    };
+    let sy = if pin1.origin.y > f64::EPSILON {
+        1.0
+    } else {
+        -1.0
+    };
+    let dx = sx * (extent.x / 2.0 + space);
+    let dy = sy * (extent.y / 2.0 + space);
+
+    let candidates = [(dx, dy), (dx, 0.0), (0.0, dy)];
+    let (dx, dy) = candidates
+        .iter()
+        .find(|(dx, dy)| {
+            let center = Point::new(pin1.origin.x + dx, pin1.origin.y + dy);
+            !pads
+                .iter()
+                .filter(|pad| !std::ptr::eq(*pad, pin1))
+                .any(|pad| {
+                    let extent = pad.extent();
+                    let space = clearance + pad.mask + size / 2.0;
+                    (center.x - pad.origin.x).abs() < extent.x / 2.0 + space
+                        && (center.y - pad.origin.y).abs() < extent.y / 2.0 + space
+                })
+        })
+        .unwrap_or(&candidates[0]);
+    let center = Point::new(pin1.origin.x + dx, pin1.origin.y + dy);
+    draw_polarity_mark(drawing, mark, center, lib_cfg);
 }
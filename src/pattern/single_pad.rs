@@ -0,0 +1,103 @@
+use std::f64::consts::PI;
+
+use crate::config::Config;
+use crate::drawing::*;
+use crate::packages::PackageType;
+
+use super::{courtyard, mask, silkscreen};
+
+/// Pattern with a single pad: fiducial, test point or mounting hole.
+#[derive(Debug, Default)]
+pub struct SinglePad {
+    pad: Pad,
+    mask_diameter: Option<f64>,
+    keep_out: Option<f64>,
+    outline: bool,
+    vias: Option<(usize, Pad)>,
+}
+
+impl SinglePad {
+    /// Draws single pad pattern.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let mut pads = vec![self.pad.clone()];
+        mask::calc(&mut pads, lib_cfg);
+        if let Some(mask_diameter) = self.mask_diameter {
+            pads[0].mask = (mask_diameter - pads[0].size.x) / 2.0;
+        }
+
+        if let Some((count, via)) = &self.vias {
+            // Vias are placed in the middle of the annular ring
+            let hole = self.pad.hole.as_ref().map(|h| h.x).unwrap_or_default();
+            let r = (hole + self.pad.size.x) / 4.0;
+            for i in 0..*count {
+                let a = 2.0 * PI * i as f64 / *count as f64;
+                pads.push(
+                    via.clone()
+                        .name(&self.pad.name)
+                        .origin(r * a.cos(), r * a.sin()),
+                );
+            }
+        }
+
+        if self.outline {
+            let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+            let clearance = lib_cfg
+                .get_f64("pattern.clearance.pad-to-silkscreen")
+                .unwrap();
+            let pad = &pads[0];
+            let radius = pad.size.x / 2.0 + pad.mask.max(0.0) + clearance + line_width / 2.0;
+            silkscreen::draw_circle(drawing, Point::new(0.0, 0.0), radius, &pads, lib_cfg);
+        }
+
+        if let Some(diameter) = self.keep_out {
+            let line_width = lib_cfg.get_f64("pattern.line-width.courtyard").unwrap();
+            drawing.add_circle(
+                Circle::new(0.0, 0.0, diameter / 2.0)
+                    .width(line_width)
+                    .layer(Layer::COURTYARD_TOP),
+            );
+        } else {
+            courtyard::draw(
+                drawing,
+                &pads,
+                None,
+                courtyard::excess(PackageType::Unknown, lib_cfg),
+                lib_cfg,
+            );
+        }
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `SinglePad` with a keep-out area of the specified diameter on courtyard.
+    pub fn keep_out(mut self, diameter: f64) -> Self {
+        self.keep_out = Some(diameter);
+        self
+    }
+
+    /// Builds a `SinglePad` with a mask opening of the specified diameter.
+    pub fn mask_opening(mut self, diameter: f64) -> Self {
+        self.mask_diameter = Some(diameter);
+        self
+    }
+
+    /// Builds a `SinglePad` with a silkscreen circle around the pad.
+    pub fn outline(mut self) -> Self {
+        self.outline = true;
+        self
+    }
+
+    /// Builds a `SinglePad` with modified pad.
+    pub fn pad(mut self, pad: Pad) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    /// Builds a `SinglePad` with a ring of vias around the hole.
+    pub fn vias(mut self, count: usize, via: Pad) -> Self {
+        self.vias = Some((count, via));
+        self
+    }
+}
@@ -1,12 +1,16 @@
 use crate::config::Config;
 use crate::drawing::*;
 
-use super::{mask, silkscreen, PadProperties};
+use super::{assembly, courtyard, mask, silkscreen, PadProperties};
 
 #[derive(Debug, Default)]
 pub struct TwoPin {
     pad_props: PadProperties,
     body: Rect,
+    polarized: bool,
+    pair_count: usize,
+    pitch: f64,
+    polarity_mark: Option<String>,
 }
 
 impl TwoPin {
@@ -20,29 +24,248 @@ impl TwoPin {
     }
 
     /// Draws two pin pattern.
+    ///
+    /// Pads of an array go downwards along the left side and then upwards along the right one.
     pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
         super::add_attributes(drawing, lib_cfg);
 
-        let pad_left = Pad::new("1")
+        let count = self.pair_count.max(1);
+        let x = self.pad_props.distance / 2.0;
+        let y0 = -self.pitch * (count as f64 - 1.0) / 2.0;
+        let pad = Pad::new("")
             .shape(PadShape::Rect)
             .size(self.pad_props.size.x, self.pad_props.size.y)
-            .origin(-self.pad_props.distance / 2.0, 0.0)
             .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP);
-        let pad_right = pad_left
-            .clone()
-            .name("2")
-            .origin(self.pad_props.distance / 2.0, 0.0);
 
-        let mut pads = vec![pad_left, pad_right];
+        let mut pads = Vec::with_capacity(2 * count);
+        for i in 0..count {
+            let y = y0 + self.pitch * i as f64;
+            pads.push(pad.clone().name(&(i + 1).to_string()).origin(-x, y));
+        }
+        for i in 0..count {
+            let y = -y0 - self.pitch * i as f64;
+            pads.push(pad.clone().name(&(count + i + 1).to_string()).origin(x, y));
+        }
         mask::calc(&mut pads, lib_cfg);
         silkscreen::draw_body(drawing, &self.body, &pads, lib_cfg);
+        if self.pair_count > 0 {
+            // Arrays have a pin 1 mark instead of the polarity
+            if let Some(mark) = &self.polarity_mark {
+                silkscreen::draw_pin1_mark(drawing, mark, &pads[0], &pads, lib_cfg);
+            }
+        } else if self.polarized {
+            match self.polarity_mark.as_deref() {
+                None | Some("bar") => self.draw_cathode_band(drawing, &pads[0], lib_cfg),
+                Some(mark) => silkscreen::draw_pin1_mark(drawing, mark, &pads[0], &pads, lib_cfg),
+            }
+        }
+
+        let pin1 = match (self.pair_count, &self.polarity_mark) {
+            (0, _) | (_, None) => None,
+            _ => Some(&pads[0]),
+        };
+        assembly::draw_body(drawing, &self.body, pin1, lib_cfg);
+        if self.polarized && self.pair_count == 0 {
+            // Cathode band inside the body
+            let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+            let x = self.body.p.0.x + (self.body.p.1.x - self.body.p.0.x) / 5.0;
+            drawing.add_line(
+                Line::new(x, self.body.p.0.y, x, self.body.p.1.y)
+                    .width(line_width)
+                    .layer(Layer::ASSEMBLY_TOP),
+            );
+        }
+
+        courtyard::draw(
+            drawing,
+            &pads,
+            Some(&self.body),
+            self.pad_props.courtyard,
+            lib_cfg,
+        );
 
         drawing.add_pads(pads);
     }
 
+    /// Builds a `TwoPin` array of the specified number of pad pairs with the specified pitch.
+    pub fn pairs(mut self, count: usize, pitch: f64) -> Self {
+        self.pair_count = count;
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `TwoPin` with modified polarity.
+    ///
+    /// Pin 1 of a polarized part is marked with a band, it is the cathode of a diode or the
+    /// anode of a tantalum capacitor. Arrays are not polarized.
+    pub fn polarized(mut self, polarized: bool) -> Self {
+        self.polarized = polarized;
+        self
+    }
+
+    /// Builds a `TwoPin` with a polarity mark near pin 1 of a polarized part or an array.
+    ///
+    /// Mark `bar` (default for polarized parts) is a band along the pin 1 side.
+    pub fn polarity_mark(mut self, mark: &str) -> Self {
+        self.polarity_mark = Some(mark.to_string());
+        self
+    }
+
     /// Builds a `TwoPin` with modified pad properties.
     pub fn pad_properties(mut self, pad_props: PadProperties) -> Self {
         self.pad_props = pad_props;
         self
     }
+
+    // Draw cathode band on silkscreen outside pin 1
+    fn draw_cathode_band(&self, drawing: &mut Drawing, pad: &Pad, lib_cfg: &Config) {
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let clearance = lib_cfg
+            .get_f64("pattern.clearance.pad-to-silkscreen")
+            .unwrap();
+        let space = clearance + pad.mask + line_width / 2.0;
+        let x = (self.body.p.0.x - line_width / 2.0).min(pad.origin.x - pad.size.x / 2.0 - space);
+        let y = (self.body.p.1.y + line_width / 2.0).max(pad.size.y / 2.0 + space);
+        drawing.add_line(
+            Line::new(x, -y, x, y)
+                .width(line_width)
+                .layer(Layer::SILKSCREEN_TOP),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Get pads of a drawn pattern
+    fn pads(two_pin: &TwoPin) -> Vec<Pad> {
+        let mut drawing = Drawing::new();
+        two_pin.draw(&mut drawing, &load_config!("../qeda.yml"));
+        drawing
+            .elements
+            .into_iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn single() {
+        let pad_props = PadProperties {
+            size: Size::new(2.2, 1.6),
+            distance: 3.4,
+            ..Default::default()
+        };
+        let pads = pads(&TwoPin::default().pad_properties(pad_props));
+        assert_eq!(pads.len(), 2);
+        assert_eq!(pads[0].name, "1");
+        assert_eq!((pads[0].origin.x, pads[0].origin.y), (-1.7, 0.0));
+        assert_eq!(pads[1].name, "2");
+        assert_eq!((pads[1].origin.x, pads[1].origin.y), (1.7, 0.0));
+        assert_eq!((pads[1].size.x, pads[1].size.y), (2.2, 1.6));
+    }
+
+    #[test]
+    fn pairs() {
+        let pad_props = PadProperties {
+            size: Size::new(0.84, 0.4),
+            distance: 1.78,
+            ..Default::default()
+        };
+        let two_pin = TwoPin::default().pad_properties(pad_props).pairs(4, 0.8);
+        let origins: Vec<_> = pads(&two_pin)
+            .iter()
+            .map(|p| (p.name.clone(), p.origin.x, (p.origin.y * 1e6).round() / 1e6))
+            .collect();
+
+        // Down the left side and up the right one
+        let expected = [
+            ("1", -0.89, -1.2),
+            ("2", -0.89, -0.4),
+            ("3", -0.89, 0.4),
+            ("4", -0.89, 1.2),
+            ("5", 0.89, 1.2),
+            ("6", 0.89, 0.4),
+            ("7", 0.89, -0.4),
+            ("8", 0.89, -1.2),
+        ];
+        assert_eq!(origins.len(), expected.len());
+        for ((name, x, y), (e_name, e_x, e_y)) in origins.iter().zip(expected) {
+            assert_eq!((name.as_str(), *x, *y), (e_name, e_x, e_y));
+        }
+    }
+
+    // Get X coordinates of silkscreen vertical lines beyond the pads and circle centers
+    fn marks(two_pin: &TwoPin) -> (Vec<f64>, Vec<(f64, f64)>) {
+        let mut drawing = Drawing::new();
+        two_pin.draw(&mut drawing, &load_config!("../qeda.yml"));
+        let mut bands = Vec::new();
+        let mut dots = Vec::new();
+        for e in drawing.elements {
+            match e {
+                Element::Line(l)
+                    if l.layer == Layer::SILKSCREEN_TOP
+                        && l.p.0.x == l.p.1.x
+                        && l.p.0.x.abs() > 2.8 =>
+                {
+                    bands.push(l.p.0.x)
+                }
+                Element::Circle(c) if c.layer == Layer::SILKSCREEN_TOP => {
+                    dots.push((c.center.x, c.center.y))
+                }
+                _ => (),
+            }
+        }
+        (bands, dots)
+    }
+
+    #[test]
+    fn polarity() {
+        let pad_props = PadProperties {
+            size: Size::new(2.2, 1.6),
+            distance: 3.4,
+            ..Default::default()
+        };
+        let two_pin = || {
+            TwoPin::default()
+                .pad_properties(pad_props.clone())
+                .body(4.3, 2.6)
+        };
+
+        let (bands, dots) = marks(&two_pin());
+        assert!(bands.is_empty() && dots.is_empty());
+
+        // Band outside pin 1 by default
+        let (bands, dots) = marks(&two_pin().polarized(true));
+        assert_eq!(bands.len(), 1);
+        assert!(bands[0] < 0.0);
+        assert!(dots.is_empty());
+
+        let (bands, dots) = marks(&two_pin().polarized(true).polarity_mark("."));
+        assert!(bands.is_empty());
+        assert_eq!(dots.len(), 1);
+        assert!(dots[0].0 < -2.8 && dots[0].1 < 0.0);
+    }
+
+    #[test]
+    fn pin1() {
+        let pad_props = PadProperties {
+            size: Size::new(0.84, 0.4),
+            distance: 1.78,
+            ..Default::default()
+        };
+        let two_pin = TwoPin::default()
+            .pad_properties(pad_props)
+            .pairs(4, 0.8)
+            .body(1.6, 3.2)
+            .polarized(true)
+            .polarity_mark(".");
+        let (bands, dots) = marks(&two_pin);
+        assert!(bands.is_empty());
+        assert_eq!(dots.len(), 1);
+        assert!(dots[0].0 < -0.89 && dots[0].1 < -1.2);
+    }
 }
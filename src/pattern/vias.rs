@@ -0,0 +1,139 @@
+use crate::config::Config;
+use crate::drawing::{Layer, Pad};
+
+use super::paste;
+
+/// Fills a thermal pad with an array of vias if `pattern.thermal-via.enabled` is set.
+///
+/// Settings in `pattern.thermal-via` of the component override the library ones. Vias are
+/// through-hole pads with the same name as the thermal pad, they keep `ring` plus
+/// `pattern.clearance.pad-to-mask` from the pad edges. Open vias have mask openings, tented
+/// and plugged ones are covered by the mask. Tenting affects the bottom side only since the
+/// top one is inside the mask opening of the thermal pad. Paste is placed between the vias
+/// unless they are plugged.
+pub fn add(pad: &mut Pad, comp_cfg: &Config, lib_cfg: &Config) -> Vec<Pad> {
+    let enabled = comp_cfg
+        .get_bool("pattern.thermal-via.enabled")
+        .unwrap_or_else(|_| lib_cfg.get_bool("pattern.thermal-via.enabled").unwrap());
+    if !enabled {
+        return Vec::new();
+    }
+    let get_f64 = |key: &str| {
+        comp_cfg
+            .get_f64(key)
+            .unwrap_or_else(|_| lib_cfg.get_f64(key).unwrap())
+    };
+    let pitch = get_f64("pattern.thermal-via.pitch");
+    let drill = get_f64("pattern.thermal-via.drill");
+    let ring = get_f64("pattern.thermal-via.ring");
+    let style = comp_cfg
+        .get_str("pattern.thermal-via.style")
+        .unwrap_or_else(|_| lib_cfg.get_str("pattern.thermal-via.style").unwrap());
+
+    let mask = lib_cfg.get_f64("pattern.clearance.pad-to-mask").unwrap();
+    let mut via = Pad::new(&pad.name).via(drill, ring);
+    let margin = ring + mask;
+    let xs = positions(pad.size.x, via.size.x, pitch, margin);
+    let ys = positions(pad.size.y, via.size.y, pitch, margin);
+    if xs.is_empty() || ys.is_empty() {
+        return Vec::new();
+    }
+
+    if style == "open" {
+        via.layers |= Layer::MASK_TOP | Layer::MASK_BOTTOM;
+        via.mask = mask;
+    }
+    if style != "plugged" {
+        let keep = via.size.x / 2.0 + mask;
+        pad.paste_windows = paste::windows_between(&pad.size, &xs, &ys, keep, comp_cfg, lib_cfg);
+        if pad.paste_windows.is_empty() {
+            // No windows would mean the paste over the whole pad
+            pad.layers -= Layer::PASTE_TOP | Layer::PASTE_BOTTOM;
+        }
+    }
+
+    let mut vias = Vec::with_capacity(xs.len() * ys.len());
+    for y in &ys {
+        for x in &xs {
+            vias.push(via.clone().origin(pad.origin.x + x, pad.origin.y + y));
+        }
+    }
+    vias
+}
+
+// Get via positions along a pad side: as many vias as fit with the pitch keeping the margin
+// from the pad edges, centered
+fn positions(side: f64, diameter: f64, pitch: f64, margin: f64) -> Vec<f64> {
+    let room = side - 2.0 * margin - diameter;
+    if room < -1e-6 || pitch <= 0.0 {
+        return Vec::new();
+    }
+    let count = (room.max(0.0) / pitch + 1e-6).floor() as usize + 1;
+    let x0 = -pitch * (count as f64 - 1.0) / 2.0;
+    (0..count).map(|i| x0 + pitch * i as f64).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::PadShape;
+    use crate::error::Result;
+
+    // Fill a 3.4 mm thermal pad with vias of the specified style
+    fn fill(style: &str) -> Result<(Pad, Vec<Pad>)> {
+        let comp_cfg = Config::from_yaml(&format!(
+            "pattern: {{thermal-via: {{enabled: true, style: {}}}}}",
+            style
+        ))?;
+        let mut pad = Pad::new("EP")
+            .shape(PadShape::Rect)
+            .size(3.4, 3.4)
+            .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP);
+        let vias = add(&mut pad, &comp_cfg, &load_config!("../qeda.yml"));
+        Ok((pad, vias))
+    }
+
+    #[test]
+    fn styles() -> Result<()> {
+        let paste = Layer::PASTE_TOP | Layer::PASTE_BOTTOM;
+        let mask = Layer::MASK_TOP | Layer::MASK_BOTTOM;
+
+        // Tented vias are covered by the mask, paste is between them
+        let (pad, vias) = fill("tented")?;
+        assert_eq!(vias.len(), 9);
+        for via in &vias {
+            assert_eq!(via.name, "EP");
+            assert_eq!(via.layers, Layer::COPPER_TOP | Layer::COPPER_BOTTOM);
+            assert_eq!(via.mask, 0.0);
+        }
+        assert!(!pad.paste_windows.is_empty());
+        assert!(pad.layers.contains(Layer::PASTE_TOP));
+
+        let (pad, vias) = fill("open")?;
+        for via in &vias {
+            assert!(via.layers.contains(mask));
+            assert!(!via.layers.intersects(paste));
+            assert_eq!(via.mask, 0.05);
+        }
+        assert!(!pad.paste_windows.is_empty());
+
+        // Paste covers the whole pad over plugged vias
+        let (pad, vias) = fill("plugged")?;
+        for via in &vias {
+            assert!(!via.layers.intersects(paste | mask));
+        }
+        assert!(pad.paste_windows.is_empty());
+        assert!(pad.layers.contains(Layer::PASTE_TOP));
+        Ok(())
+    }
+
+    #[test]
+    fn positions() {
+        // 0.6 mm vias with 0.2 mm margin at 1.2 mm pitch
+        assert_eq!(super::positions(3.4, 0.6, 1.2, 0.2), [-1.2, 0.0, 1.2]);
+        assert_eq!(super::positions(3.3, 0.6, 1.2, 0.2), [-0.6, 0.6]);
+        assert_eq!(super::positions(1.0, 0.6, 1.2, 0.2), [0.0]);
+        assert!(super::positions(0.9, 0.6, 1.2, 0.2).is_empty());
+        assert!(super::positions(3.2, 0.6, 0.0, 0.2).is_empty());
+    }
+}
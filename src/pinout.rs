@@ -85,14 +85,14 @@ impl Pinout {
     pub fn new() -> Self {
         let mut letters: Vec<String> = vec![
             "", "A", "B", "C", "D", "E", "F", "G", "H", "J", "K", "L", "M", "N", "P", "R", "T",
-            "U", "V", "W", "Y", "Z",
+            "U", "V", "W", "Y",
         ]
         .iter()
         .map(|s| s.to_string())
         .collect();
         let len = letters.len();
         for i in 1..len {
-            for j in i..len {
+            for j in 1..len {
                 letters.push(format!("{}{}", letters[i], letters[j]));
             }
         }
@@ -153,6 +153,72 @@ impl Pinout {
         }
     }
 
+    /// Parses pin number(s) from the `Config`'s value.
+    ///
+    /// Ranges like `A1..B10` are expanded using JEDEC row letters.
+    pub fn parse_number(&self, number: &Value) -> Result<Vec<String>> {
+        let mut result = Vec::new();
+        match number {
+            Value::Number(n) => {
+                // Numbers from YAML are stored as floats, integers should be rendered without fraction
+                match n.as_f64() {
+                    Some(f) if f.fract().abs() < f64::EPSILON => {
+                        result.push((f as i64).to_string())
+                    }
+                    _ => result.push(n.to_string()),
+                }
+            }
+            Value::String(s) => {
+                let s = s.to_uppercase();
+                let re = Regex::new(r"([A-Z]{0,2})(\d+)\s*\.\.\s*([A-Z]{0,2})(\d+)").unwrap();
+                if re.is_match(&s) {
+                    let caps = re
+                        .captures(&s)
+                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
+                    let row_begin = self
+                        .letters
+                        .iter()
+                        .position(|s| s.eq(&caps[1]))
+                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
+                    let mut row_end = self
+                        .letters
+                        .iter()
+                        .position(|s| s.eq(&caps[3]))
+                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
+                    if row_end < row_begin {
+                        row_end = row_begin;
+                    }
+                    let col_begin = caps[2].parse::<usize>()?;
+                    let col_end = caps[4].parse::<usize>()?;
+                    ensure!(
+                        col_begin < col_end,
+                        QedaError::InvalidPinNumber(s.to_string())
+                    );
+                    for row in row_begin..=row_end {
+                        for col in col_begin..=col_end {
+                            result.push(format!("{}{}", self.letters[row], col));
+                        }
+                    }
+                } else {
+                    result.push(s);
+                }
+            }
+            Value::Array(a) => {
+                for n in a {
+                    let mut sub_numbers = self.parse_number(n)?;
+                    result.append(&mut sub_numbers);
+                }
+            }
+            _ => (), // TODO: Return the error about unexpected type
+        }
+        Ok(result)
+    }
+
+    /// Returns the JEDEC name of a grid row with the specified zero-based index (`A`, `B`, ..., `AA`, ...).
+    pub fn row_name(&self, index: usize) -> Option<&str> {
+        self.letters.get(index + 1).map(|s| s.as_str())
+    }
+
     // Add pins from `Config`'s value
     fn add_pins(&mut self, name: &str, value: &Value) -> Result<Vec<usize>> {
         let mut result = Vec::new();
@@ -224,59 +290,6 @@ impl Pinout {
 
         Ok(result)
     }
-
-    // Parse pin number(s) from string
-    fn parse_number(&self, number: &Value) -> Result<Vec<String>> {
-        let mut result = Vec::new();
-        match number {
-            Value::Number(n) => {
-                result.push(n.to_string());
-            }
-            Value::String(s) => {
-                let s = s.to_uppercase();
-                let re = Regex::new(r"([A-Z]{0,2})(\d+)\s*\.\.\s*([A-Z]{0,2})(\d+)").unwrap();
-                if re.is_match(&s) {
-                    let caps = re
-                        .captures(&s)
-                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
-                    let row_begin = self
-                        .letters
-                        .iter()
-                        .position(|s| s.eq(&caps[1]))
-                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
-                    let mut row_end = self
-                        .letters
-                        .iter()
-                        .position(|s| s.eq(&caps[3]))
-                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
-                    if row_end < row_begin {
-                        row_end = row_begin;
-                    }
-                    let col_begin = caps[2].parse::<usize>()?;
-                    let col_end = caps[4].parse::<usize>()?;
-                    ensure!(
-                        col_begin < col_end,
-                        QedaError::InvalidPinNumber(s.to_string())
-                    );
-                    for row in row_begin..=row_end {
-                        for col in col_begin..=col_end {
-                            result.push(format!("{}{}", self.letters[row], col));
-                        }
-                    }
-                } else {
-                    result.push(s);
-                }
-            }
-            Value::Array(a) => {
-                for n in a {
-                    let mut sub_numbers = self.parse_number(n)?;
-                    result.append(&mut sub_numbers);
-                }
-            }
-            _ => (), // TODO: Return the error about unexpected type
-        }
-        Ok(result)
-    }
 }
 
 impl Default for Pinout {
@@ -342,4 +355,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn numbers() -> Result<()> {
+        let pinout_yaml = r"
+        pinout:
+          A: 1
+          B: [2, 3.5]
+          C: 10..11
+        ";
+        let pinout = Pinout::from_config(&Config::from_yaml(pinout_yaml)?)?;
+        let numbers: Vec<&str> = pinout.pins.iter().map(|p| p.number.as_str()).collect();
+
+        // Integers are stored as floats by the config but rendered without fraction
+        assert_eq!(numbers, vec!["1", "2", "3.5", "10", "11"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rows() -> Result<()> {
+        let pinout = Pinout::new();
+        let rows: Vec<&str> = (0..44).filter_map(|i| pinout.row_name(i)).collect();
+        assert_eq!(rows[..3], ["A", "B", "C"]);
+        assert_eq!(rows[18..22], ["W", "Y", "AA", "AB"]);
+        assert_eq!(rows[37..], ["AV", "AW", "AY", "BA", "BB", "BC", "BD"]);
+
+        let numbers = pinout.parse_number(&Value::String("A1..B2".to_string()))?;
+        assert_eq!(numbers, vec!["A1", "A2", "B1", "B2"]);
+        let numbers = pinout.parse_number(&Value::String("AY1..BA2".to_string()))?;
+        assert_eq!(numbers, vec!["AY1", "AY2", "BA1", "BA2"]);
+
+        Ok(())
+    }
 }